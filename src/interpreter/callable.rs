@@ -1,24 +1,54 @@
-use std::fmt;
+use std::{cell::RefCell, fmt, rc::Rc};
 
-use crate::{lexer::{Literal, Token}, parser::stmt::Stmt};
+use crate::{lexer::{Literal, Token}, parser::stmt::{Param, Stmt}};
 
-use super::{environment::Environment, Interpreter};
+use super::{
+    environment::Environment,
+    generator::{contains_yield, Generator},
+    interpret_error::InterpretError,
+    Interpreter,
+};
 
 pub trait Callable: fmt::Debug {
-    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>) -> Option<Literal>;
-    fn arity(&self) -> usize;
+    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>, named: Vec<(Token, Literal)>) -> Option<Literal>;
+    fn min_arity(&self) -> usize;
+    // `None` means any number of extra arguments is accepted
+    fn max_arity(&self) -> Option<usize>;
+}
+
+// Describes the accepted argument count, e.g. `2`, `1 to 3` or `at least 1`
+pub fn describe_arity(min: usize, max: Option<usize>) -> String {
+    match max {
+        Some(max) if max == min => format!("{}", min),
+        Some(max) => format!("{} to {}", min, max),
+        None => format!("at least {}", min),
+    }
 }
 
-#[derive(Debug)]
 pub struct NepLatFunc {
     name: Token,
-    params: Vec<Token>,
+    params: Vec<Param>,
     body: Vec<Box<Stmt>>,
+    // The scope the function was declared in
+    closure: Rc<RefCell<Environment>>,
+    // A body with a `yield` makes calls return a generator instead of running it
+    is_generator: bool,
 }
 
 impl NepLatFunc {
-    pub fn new(name: Token, params: Vec<Token>, body: Vec<Box<Stmt>>) -> Self {
-        NepLatFunc { name, params, body }
+    pub fn new(name: Token, params: Vec<Param>, body: Vec<Box<Stmt>>, closure: Rc<RefCell<Environment>>) -> Self {
+        let is_generator = body.iter().any(|stmt| contains_yield(stmt));
+        NepLatFunc { name, params, body, closure, is_generator }
+    }
+}
+
+// The closure usually holds the function itself, so it is left out to avoid endless output
+impl fmt::Debug for NepLatFunc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NepLatFunc")
+            .field("name", &self.name)
+            .field("params", &self.params)
+            .finish()
     }
 }
 
@@ -28,24 +58,114 @@ impl fmt::Display for NepLatFunc {
     }
 }
 
+// A method read off a value without calling it, e.g. `gen.next`; calls pass the value as the first argument
+pub struct BoundMethod {
+    receiver: Literal,
+    method: Rc<dyn Callable>,
+}
+
+impl BoundMethod {
+    pub fn new(receiver: Literal, method: Rc<dyn Callable>) -> Self {
+        BoundMethod { receiver, method }
+    }
+
+    pub fn receiver(&self) -> &Literal {
+        &self.receiver
+    }
+}
+
+impl fmt::Debug for BoundMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<bound {:?} of {}>", self.method, self.receiver)
+    }
+}
+
+impl fmt::Display for BoundMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Callable for BoundMethod {
+    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>, named: Vec<(Token, Literal)>) -> Option<Literal> {
+        let mut bound_arguments = Vec::with_capacity(arguments.len() + 1);
+        bound_arguments.push(self.receiver.clone());
+        bound_arguments.extend(arguments);
+        self.method.call(interpreter, bound_arguments, named)
+    }
+
+    fn min_arity(&self) -> usize {
+        self.method.min_arity().saturating_sub(1)
+    }
+
+    fn max_arity(&self) -> Option<usize> {
+        self.method.max_arity().map(|max| max.saturating_sub(1))
+    }
+}
+
 impl Callable for NepLatFunc {
-    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>) -> Option<Literal> {
-        // Create a new environment with the interpreter's current environment as its enclosing one
-        let mut environment = Environment::new_with_env(Box::new(interpreter.get_env()));
-
-        // Define each parameter in the new environment, binding them to the provided arguments
-        for (i, param) in self.params.iter().enumerate() {
-            if i < arguments.len() {
-                environment.define(&param.lexeme(), arguments[i].clone());
+    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>, mut named: Vec<(Token, Literal)>) -> Option<Literal> {
+        // Create a new environment enclosed by the scope the function was declared in
+        let mut environment = Environment::new_with_env(Rc::clone(&self.closure));
+        let mut arguments = arguments.into_iter();
+        let mut body = Vec::new();
+
+        // Bind each parameter from a positional argument, then a named argument, then its default
+        for param in &self.params {
+            let param_name = param.name.lexeme();
+
+            if param.rest {
+                environment.define(param_name, Literal::new_list(arguments.by_ref().collect()));
+            } else if let Some(value) = arguments.next() {
+                environment.define(param_name, value);
+            } else if let Some(index) = named.iter().position(|(name, _)| name.lexeme() == param_name) {
+                let (_, value) = named.remove(index);
+                environment.define(param_name, value);
+            } else if let Some(default) = &param.default {
+                // Defaults run inside the function scope so they can refer to earlier parameters
+                body.push(Box::new(Stmt::Var(param.name.clone(), Some(default.clone()))));
+            } else {
+                interpreter.report_error(InterpretError::ArgumentMismatch(format!(
+                    "Missing argument for parameter '{}' of {}.",
+                    param_name, self
+                )));
+                return None;
             }
         }
 
+        if let Some((name, _)) = named.first() {
+            let message = if self.params.iter().any(|param| param.name.lexeme() == name.lexeme()) {
+                format!("Argument '{}' of {} was given more than once.", name.lexeme(), self)
+            } else {
+                format!("{} has no parameter named '{}'.", self, name.lexeme())
+            };
+            interpreter.report_error(InterpretError::ArgumentMismatch(message));
+            return None;
+        }
+
+        body.extend(self.body.iter().cloned());
+
+        if self.is_generator {
+            let generator = Generator::new(self.name.lexeme(), body, environment);
+            return Some(Literal::Generator(Rc::new(generator)));
+        }
+
         // Execute the function body in the new environment
-        interpreter.execute_block(self.body.clone(), environment);
-        None
+        interpreter.call_function(self.name.lexeme(), body, environment)
     }
 
-    fn arity(&self) -> usize {
-        self.params.len()
+    fn min_arity(&self) -> usize {
+        self.params
+            .iter()
+            .filter(|param| !param.rest && param.default.is_none())
+            .count()
     }
-}
\ No newline at end of file
+
+    fn max_arity(&self) -> Option<usize> {
+        if self.params.iter().any(|param| param.rest) {
+            None
+        } else {
+            Some(self.params.len())
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::lexer::Literal;
+
+use super::{
+    environment::Environment,
+    interpret_error::InterpretError,
+    module::Namespace,
+    native::{Arguments, Arity, NativeFunction},
+    Interpreter,
+};
+
+const FIRST_BS_YEAR: i64 = 2000;
+
+// Days in each month of the Bikram Sambat years 2000 to 2090, Baisakh first
+const BS_MONTH_DAYS: [[u8; 12]; 91] = [
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2000
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2001
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2002
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2003
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2004
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2005
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2006
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2007
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], // 2008
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2009
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2010
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2011
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2012
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2013
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2014
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2015
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2016
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2017
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2018
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2019
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2020
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2021
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2022
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2023
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2024
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2025
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2026
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2027
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2028
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], // 2029
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2030
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2031
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2032
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2033
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2034
+    [30, 32, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], // 2035
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2036
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2037
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2038
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2039
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2040
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2041
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2042
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2043
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2044
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2045
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2046
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2047
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2048
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2049
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2050
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2051
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2052
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2053
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2054
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2055
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], // 2056
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2057
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2058
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2059
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2060
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2061
+    [30, 32, 31, 32, 31, 31, 29, 30, 29, 30, 29, 31], // 2062
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2063
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2064
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2065
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], // 2066
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2067
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2068
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2069
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2070
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2071
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2072
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2073
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2074
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2075
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2076
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2077
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2078
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2079
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2080
+    [31, 31, 32, 32, 31, 30, 30, 30, 29, 30, 30, 30], // 2081
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], // 2082
+    [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], // 2083
+    [31, 31, 32, 31, 31, 30, 30, 30, 29, 30, 30, 30], // 2084
+    [31, 32, 31, 32, 30, 31, 30, 30, 29, 30, 30, 30], // 2085
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], // 2086
+    [31, 31, 32, 31, 31, 31, 30, 30, 29, 30, 30, 30], // 2087
+    [30, 31, 32, 32, 30, 31, 30, 30, 29, 30, 30, 30], // 2088
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], // 2089
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 30, 30], // 2090
+];
+
+// 1 Baisakh 2000 BS was 14 April 1943 AD
+const FIRST_BS_DAY: (i64, i64, i64) = (1943, 4, 14);
+
+const MONTHS: [&str; 12] = [
+    "Baisakh", "Jestha", "Asar", "Shrawan", "Bhadra", "Asoj", "Kartik", "Mangsir", "Poush", "Magh", "Falgun", "Chaitra",
+];
+
+const WEEKDAYS: [&str; 7] = ["Aaitabar", "Sombar", "Mangalbar", "Budhabar", "Bihibar", "Sukrabar", "Sanibar"];
+
+// Nepal Standard Time is UTC+5:45
+const NEPAL_OFFSET_SECONDS: i64 = (5 * 60 + 45) * 60;
+
+// Days either side of 1970 that still fall in AD years 1 to 9999
+const MAX_DAYS: u64 = 3_000_000;
+
+type DateFunction = fn(&Arguments) -> Result<Literal, InterpretError>;
+
+// The `date`/`miti` module; dates are maps like `{year: 2081, month: 1, day: 15, weekday: 6}`
+pub fn namespace() -> Namespace {
+    let functions: [(&str, Arity, DateFunction); 8] = [
+        ("today", Arity::exact(0), |_| bs_date(nepal_time()?.0)),
+        ("now", Arity::exact(0), |_| now()),
+        ("to_bs", Arity::range(1, 3), |arguments| bs_date(ad_to_days(date_argument(arguments)?)?)),
+        ("to_ad", Arity::range(1, 3), |arguments| Ok(ad_date(bs_to_days(date_argument(arguments)?)?))),
+        ("format", Arity::exact(1), |arguments| {
+            let days = bs_to_days(date_fields(arguments, 0)?)?;
+            let (year, month, day) = bs_from_days(days)?;
+            Ok(Literal::StringLiteral(format!(
+                "{} {} {}, {}",
+                WEEKDAYS[weekday(days)],
+                day,
+                MONTHS[month as usize - 1],
+                year
+            )))
+        }),
+        ("add_days", Arity::exact(2), |arguments| {
+            let count = arguments.integer(1)?;
+            let days = bs_to_days(date_fields(arguments, 0)?)?
+                .checked_add(count)
+                .ok_or_else(|| date_error(format!("Cannot add {} days to a date", count)))?;
+            bs_date(days)
+        }),
+        ("days_between", Arity::exact(2), |arguments| {
+            let from = bs_to_days(date_fields(arguments, 0)?)?;
+            let to = bs_to_days(date_fields(arguments, 1)?)?;
+            Ok(Literal::NumberLiteral((to - from) as f64))
+        }),
+        ("days_in_month", Arity::exact(2), |arguments| {
+            let (year, month) = (arguments.integer(0)?, arguments.integer(1)?);
+            Ok(Literal::NumberLiteral(bs_month_days(year, month)? as f64))
+        }),
+    ];
+
+    let mut environment = Environment::new();
+    let mut exports = Vec::new();
+    for (name, arity, function) in functions {
+        let native = NativeFunction::new(name, arity, move |_: &mut Interpreter, arguments| function(&arguments));
+        environment.define(name, Literal::Callable(Rc::new(native)));
+        exports.push(name.to_string());
+    }
+    Namespace::new("date", Rc::new(RefCell::new(environment)), exports)
+}
+
+fn date_error(message: String) -> InterpretError {
+    InterpretError::DateError(message)
+}
+
+// Days since 1 January 1970 AD of a Gregorian date, using Howard Hinnant's `days_from_civil`
+fn ad_to_days((year, month, day): (i64, i64, i64)) -> Result<i64, InterpretError> {
+    // Checked first so the arithmetic below cannot overflow
+    if !(1..=9999).contains(&year) {
+        return Err(date_error(format!("Only AD years 1 to 9999 are supported, not {}", year)));
+    }
+    if !(1..=12).contains(&month) || day < 1 || day > ad_month_days(year, month) {
+        return Err(date_error(format!("{}-{:02}-{:02} is not a valid AD date", year, month, day)));
+    }
+
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Ok(era * 146097 + day_of_era - 719468)
+}
+
+// The Gregorian date a day count falls on, the inverse of `ad_to_days`
+fn ad_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn ad_month_days(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn bs_year_months(year: i64) -> Result<&'static [u8; 12], InterpretError> {
+    usize::try_from(year - FIRST_BS_YEAR)
+        .ok()
+        .and_then(|index| BS_MONTH_DAYS.get(index))
+        .ok_or_else(|| {
+            date_error(format!(
+                "Only Bikram Sambat years {} to {} are supported, not {}",
+                FIRST_BS_YEAR,
+                FIRST_BS_YEAR + BS_MONTH_DAYS.len() as i64 - 1,
+                year
+            ))
+        })
+}
+
+fn bs_month_days(year: i64, month: i64) -> Result<i64, InterpretError> {
+    let months = bs_year_months(year)?;
+    if !(1..=12).contains(&month) {
+        return Err(date_error(format!("{} is not a month; months go from 1 (Baisakh) to 12 (Chaitra)", month)));
+    }
+    Ok(months[month as usize - 1] as i64)
+}
+
+fn bs_to_days((year, month, day): (i64, i64, i64)) -> Result<i64, InterpretError> {
+    let length = bs_month_days(year, month)?;
+    if day < 1 || day > length {
+        return Err(date_error(format!("{} {} {} is not a valid BS date", year, MONTHS[month as usize - 1], day)));
+    }
+
+    let mut days = ad_to_days(FIRST_BS_DAY)?;
+    for months in &BS_MONTH_DAYS[..(year - FIRST_BS_YEAR) as usize] {
+        days += months.iter().map(|&length| length as i64).sum::<i64>();
+    }
+    days += bs_year_months(year)?[..month as usize - 1].iter().map(|&length| length as i64).sum::<i64>();
+    Ok(days + day - 1)
+}
+
+fn bs_from_days(days: i64) -> Result<(i64, i64, i64), InterpretError> {
+    let mut remaining = days.checked_sub(ad_to_days(FIRST_BS_DAY)?).unwrap_or(-1);
+    if remaining >= 0 {
+        for (index, months) in BS_MONTH_DAYS.iter().enumerate() {
+            for (month, &length) in months.iter().enumerate() {
+                if remaining < length as i64 {
+                    return Ok((FIRST_BS_YEAR + index as i64, month as i64 + 1, remaining + 1));
+                }
+                remaining -= length as i64;
+            }
+        }
+    }
+
+    let last_year = FIRST_BS_YEAR + BS_MONTH_DAYS.len() as i64 - 1;
+    // Day counts far outside any calendar have no AD date to show either
+    if days.unsigned_abs() > MAX_DAYS {
+        return Err(date_error(format!(
+            "That date is outside the Bikram Sambat years {} to {}",
+            FIRST_BS_YEAR, last_year
+        )));
+    }
+
+    let (year, month, day) = ad_from_days(days);
+    Err(date_error(format!(
+        "{}-{:02}-{:02} AD is outside the Bikram Sambat years {} to {}",
+        year, month, day, FIRST_BS_YEAR, last_year
+    )))
+}
+
+// 0 for Aaitabar (Sunday) up to 6 for Sanibar; 1 January 1970 was a Thursday
+fn weekday(days: i64) -> usize {
+    (days + 4).rem_euclid(7) as usize
+}
+
+fn date_map(fields: Vec<(&str, i64)>) -> Literal {
+    let entries = fields
+        .into_iter()
+        .map(|(name, value)| (Literal::StringLiteral(name.to_string()), Literal::NumberLiteral(value as f64)))
+        .collect();
+    Literal::Map(Rc::new(RefCell::new(entries)))
+}
+
+fn bs_date(days: i64) -> Result<Literal, InterpretError> {
+    let (year, month, day) = bs_from_days(days)?;
+    Ok(date_map(vec![("year", year), ("month", month), ("day", day), ("weekday", weekday(days) as i64)]))
+}
+
+fn ad_date(days: i64) -> Literal {
+    let (year, month, day) = ad_from_days(days);
+    date_map(vec![("year", year), ("month", month), ("day", day), ("weekday", weekday(days) as i64)])
+}
+
+// Seconds since 1970 in Nepal, split into whole days and the seconds into the day
+fn nepal_time() -> Result<(i64, i64), InterpretError> {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| date_error("The system clock is set before 1970".to_string()))?
+        .as_secs() as i64
+        + NEPAL_OFFSET_SECONDS;
+    Ok((seconds.div_euclid(86400), seconds.rem_euclid(86400)))
+}
+
+// Today's BS date with the time of day in Nepal
+fn now() -> Result<Literal, InterpretError> {
+    let (days, seconds) = nepal_time()?;
+    let (year, month, day) = bs_from_days(days)?;
+    Ok(date_map(vec![
+        ("year", year),
+        ("month", month),
+        ("day", day),
+        ("weekday", weekday(days) as i64),
+        ("hour", seconds / 3600),
+        ("minute", seconds % 3600 / 60),
+        ("second", seconds % 60),
+    ]))
+}
+
+// `year`, `month` and `day` of a date map
+fn date_fields(arguments: &Arguments, index: usize) -> Result<(i64, i64, i64), InterpretError> {
+    let Literal::Map(entries) = arguments.value(index)? else {
+        return Err(InterpretError::TypeMismatch(format!(
+            "Expected a date map as argument {}, found a {}",
+            index + 1,
+            arguments.value(index)?.type_name()
+        )));
+    };
+
+    let field = |name: &str| {
+        entries
+            .borrow()
+            .iter()
+            .find_map(|(key, value)| match (key, value) {
+                (Literal::StringLiteral(key), Literal::NumberLiteral(number)) if key == name && number.fract() == 0.0 => {
+                    Some(*number as i64)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| date_error(format!("A date needs a whole number '{}'", name)))
+    };
+    Ok((field("year")?, field("month")?, field("day")?))
+}
+
+// A date given either as a map or as `year, month, day`, where a left out day is the first of the month
+fn date_argument(arguments: &Arguments) -> Result<(i64, i64, i64), InterpretError> {
+    if arguments.len() == 1 {
+        return date_fields(arguments, 0);
+    }
+
+    let day = match arguments.get(2) {
+        Some(_) => arguments.integer(2)?,
+        None => 1,
+    };
+    Ok((arguments.integer(0)?, arguments.integer(1)?, day))
+}
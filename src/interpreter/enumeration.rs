@@ -0,0 +1,169 @@
+use std::{fmt, rc::Rc};
+
+use crate::lexer::{Literal, Token};
+
+use super::{callable::Callable, interpret_error::InterpretError, Interpreter};
+
+// The value an `enum` declaration binds; its variants are read as `Color.Rato`
+#[derive(Debug)]
+pub struct EnumType {
+    name: String,
+    // (variant name, field names) in declaration order
+    variants: Vec<(String, Vec<String>)>,
+}
+
+impl EnumType {
+    pub fn new(name: &str, variants: Vec<(String, Vec<String>)>) -> Self {
+        EnumType {
+            name: name.to_string(),
+            variants,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn variant_index(&self, name: &str) -> Option<usize> {
+        self.variants.iter().position(|(variant, _)| variant == name)
+    }
+
+    // A variant without fields is a value; one with fields is a function that builds the value
+    pub fn member(enum_type: &Rc<EnumType>, name: &str) -> Option<Literal> {
+        let index = enum_type.variant_index(name)?;
+
+        if enum_type.variants[index].1.is_empty() {
+            Some(Literal::Variant(Rc::new(Variant::new(Rc::clone(enum_type), index, Vec::new()))))
+        } else {
+            Some(Literal::Callable(Rc::new(VariantConstructor {
+                enum_type: Rc::clone(enum_type),
+                index,
+            })))
+        }
+    }
+}
+
+impl fmt::Display for EnumType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<enum {}>", self.name)
+    }
+}
+
+// One variant of an enum together with the data it carries
+#[derive(Debug)]
+pub struct Variant {
+    enum_type: Rc<EnumType>,
+    index: usize,
+    values: Vec<Literal>,
+}
+
+impl Variant {
+    pub fn new(enum_type: Rc<EnumType>, index: usize, values: Vec<Literal>) -> Self {
+        Variant { enum_type, index, values }
+    }
+
+    pub fn enum_type(&self) -> &Rc<EnumType> {
+        &self.enum_type
+    }
+
+    pub fn name(&self) -> &str {
+        &self.enum_type.variants[self.index].0
+    }
+
+    pub fn values(&self) -> &[Literal] {
+        &self.values
+    }
+
+    pub fn field(&self, name: &str) -> Option<Literal> {
+        let fields = &self.enum_type.variants[self.index].1;
+        let position = fields.iter().position(|field| field == name)?;
+        self.values.get(position).cloned()
+    }
+
+    // Variants of the same declaration with the same name; the data is compared separately
+    pub fn same_variant(&self, other: &Variant) -> bool {
+        Rc::ptr_eq(&self.enum_type, &other.enum_type) && self.index == other.index
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.enum_type.name, self.name())?;
+        if self.values.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "(")?;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// `Shape.Circle` for a variant declared as `Circle(radius)`
+#[derive(Debug)]
+pub struct VariantConstructor {
+    enum_type: Rc<EnumType>,
+    index: usize,
+}
+
+impl VariantConstructor {
+    fn fields(&self) -> &[String] {
+        &self.enum_type.variants[self.index].1
+    }
+}
+
+impl fmt::Display for VariantConstructor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<variant {}.{}>", self.enum_type.name, self.enum_type.variants[self.index].0)
+    }
+}
+
+impl Callable for VariantConstructor {
+    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>, mut named: Vec<(Token, Literal)>) -> Option<Literal> {
+        let mut arguments = arguments.into_iter();
+        let mut values = Vec::new();
+
+        // Fields are filled like parameters: positional arguments first, then by name
+        for field in self.fields() {
+            if let Some(value) = arguments.next() {
+                values.push(value);
+            } else if let Some(index) = named.iter().position(|(name, _)| name.lexeme() == field) {
+                values.push(named.remove(index).1);
+            } else {
+                interpreter.report_error(InterpretError::ArgumentMismatch(format!(
+                    "Missing value for field '{}' of {}.",
+                    field, self
+                )));
+                return None;
+            }
+        }
+
+        if let Some((name, _)) = named.first() {
+            interpreter.report_error(InterpretError::ArgumentMismatch(format!(
+                "{} has no field named '{}'.",
+                self,
+                name.lexeme()
+            )));
+            return None;
+        }
+
+        Some(Literal::Variant(Rc::new(Variant::new(
+            Rc::clone(&self.enum_type),
+            self.index,
+            values,
+        ))))
+    }
+
+    fn min_arity(&self) -> usize {
+        self.fields().len()
+    }
+
+    fn max_arity(&self) -> Option<usize> {
+        Some(self.fields().len())
+    }
+}
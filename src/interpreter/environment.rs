@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::{HashMap, HashSet}, rc::Rc};
 
 use crate::lexer::{Literal, Token};
 use super::interpret_error::InterpretError;
@@ -6,30 +6,40 @@ use super::interpret_error::InterpretError;
 #[derive(Debug, Clone)]
 pub struct Environment {
     values: HashMap<String, Literal>,
-    pub enclosing: Option<Box<Environment>>,
+    // Names in `values` declared with `const`
+    constants: HashSet<String>,
+    pub enclosing: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Environment {
     pub fn new() -> Self {
         Environment { 
             values: HashMap::new(),
+            constants: HashSet::new(),
             enclosing: None,
          }
     }
 
-    pub fn new_with_env(enclosing: Box<Environment>) -> Self {
+    pub fn new_with_env(enclosing: Rc<RefCell<Environment>>) -> Self {
         Environment {
             values: HashMap::new(),
+            constants: HashSet::new(),
             enclosing: Some(enclosing),
         }
     }
 
-    pub fn get(&self, name: &str) -> Result<&Literal, InterpretError> {
+    pub fn get(&self, name: &str) -> Result<Literal, InterpretError> {
 
         if let Some(value) = self.values.get(name) {
-            return Ok(value);
+            return Ok(value.clone());
         } else if let Some(ref enclosing_env) = self.enclosing {
-            return enclosing_env.get(name);
+            return enclosing_env.borrow().get(name);
         }
 
         Err(InterpretError::UndefinedVariable(format!(
@@ -41,11 +51,17 @@ impl Environment {
         let var_name = name.lexeme().to_string();
 
         if self.values.contains_key(&var_name) {
+            if self.constants.contains(&var_name) {
+                return Err(InterpretError::ConstantReassignment(format!(
+                    "Cannot assign to constant '{}'", var_name
+                )));
+            }
+
             self.values.insert(var_name.to_string(), value.clone());
             
             return Ok(());
-        } else if let Some(ref mut enclosing_env) = self.enclosing {
-            return enclosing_env.assign(name, value);
+        } else if let Some(ref enclosing_env) = self.enclosing {
+            return enclosing_env.borrow_mut().assign(name, value);
         }
 
         Err(InterpretError::UndefinedVariable(format!(
@@ -54,6 +70,13 @@ impl Environment {
     }
 
     pub fn define(&mut self, name: &str, value: Literal) {
+        // Redeclaring a name with `let` makes it mutable again
+        self.constants.remove(name);
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn define_constant(&mut self, name: &str, value: Literal) {
+        self.constants.insert(name.to_string());
         self.values.insert(name.to_string(), value);
     }
 }
@@ -0,0 +1,156 @@
+use std::{
+    cell::RefCell,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Component, Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::lexer::Literal;
+
+use super::{
+    environment::Environment,
+    interpret_error::InterpretError,
+    module::Namespace,
+    native::{Arguments, Arity, NativeFunction},
+    Interpreter,
+};
+
+// What scripts may touch on disk: nothing, or the files under one root directory
+#[derive(Debug, Clone, Default)]
+pub struct FileAccess {
+    root: Option<PathBuf>,
+}
+
+impl FileAccess {
+    pub fn denied() -> Self {
+        FileAccess { root: None }
+    }
+
+    pub fn new(root: &Path) -> io::Result<Self> {
+        Ok(FileAccess {
+            root: Some(fs::canonicalize(root)?),
+        })
+    }
+
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
+    pub fn read(&self, path: &str) -> Result<String, InterpretError> {
+        let full_path = self.resolve(path)?;
+        fs::read_to_string(full_path).map_err(|error| failed("read", path, error))
+    }
+
+    pub fn write(&self, path: &str, contents: &str) -> Result<(), InterpretError> {
+        let full_path = self.resolve(path)?;
+        fs::write(full_path, contents).map_err(|error| failed("write", path, error))
+    }
+
+    pub fn append(&self, path: &str, contents: &str) -> Result<(), InterpretError> {
+        let full_path = self.resolve(path)?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(full_path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|error| failed("append to", path, error))
+    }
+
+    pub fn exists(&self, path: &str) -> Result<bool, InterpretError> {
+        Ok(self.resolve(path)?.exists())
+    }
+
+    // Names of the entries in a directory, sorted
+    pub fn list(&self, path: &str) -> Result<Vec<String>, InterpretError> {
+        let full_path = self.resolve(path)?;
+        let mut names = fs::read_dir(full_path)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+                    .collect::<io::Result<Vec<String>>>()
+            })
+            .map_err(|error| failed("list", path, error))?;
+        names.sort();
+        Ok(names)
+    }
+
+    // The real location of a script's path, which must stay inside the root, links included
+    fn resolve(&self, path: &str) -> Result<PathBuf, InterpretError> {
+        let Some(root) = &self.root else {
+            return Err(InterpretError::FileError(format!(
+                "Cannot use '{}'; file access is turned off",
+                path
+            )));
+        };
+        let outside = || InterpretError::FileError(format!("Cannot use '{}'; it is outside the allowed directory", path));
+
+        let mut full_path = root.clone();
+        for component in Path::new(path).components() {
+            match component {
+                Component::Normal(part) => full_path.push(part),
+                Component::CurDir => {}
+                Component::ParentDir if full_path != *root => {
+                    full_path.pop();
+                }
+                _ => return Err(outside()),
+            }
+        }
+
+        // A link inside the root may point elsewhere, so check where the deepest existing entry really is;
+        // `symlink_metadata` also finds dangling links, which cannot be canonicalized and so are refused
+        let existing = full_path
+            .ancestors()
+            .find(|ancestor| fs::symlink_metadata(ancestor).is_ok())
+            .unwrap_or(root);
+        match fs::canonicalize(existing) {
+            Ok(real) if real.starts_with(root) => Ok(full_path),
+            _ => Err(outside()),
+        }
+    }
+}
+
+type FileFunction = fn(&FileAccess, &Arguments) -> Result<Literal, InterpretError>;
+
+fn failed(action: &str, path: &str, error: io::Error) -> InterpretError {
+    InterpretError::FileError(format!("Cannot {} '{}': {}", action, path, error))
+}
+
+// The `files`/`kagaj` module; every function goes through the interpreter's `FileAccess`
+pub fn namespace() -> Namespace {
+    let functions: [(&str, Arity, FileFunction); 5] = [
+        ("read", Arity::exact(1), |files, arguments| {
+            Ok(Literal::StringLiteral(files.read(arguments.string(0)?)?))
+        }),
+        ("write", Arity::exact(2), |files, arguments| {
+            files.write(arguments.string(0)?, &arguments.value(1)?.to_string())?;
+            Ok(Literal::NullLiteral)
+        }),
+        ("append", Arity::exact(2), |files, arguments| {
+            files.append(arguments.string(0)?, &arguments.value(1)?.to_string())?;
+            Ok(Literal::NullLiteral)
+        }),
+        ("exists", Arity::exact(1), |files, arguments| {
+            Ok(Literal::BooleanLiteral(files.exists(arguments.string(0)?)?))
+        }),
+        ("list", Arity::range(0, 1), |files, arguments| {
+            let path = match arguments.get(0) {
+                Some(_) => arguments.string(0)?,
+                None => ".",
+            };
+            let names = files.list(path)?.into_iter().map(Literal::StringLiteral).collect();
+            Ok(Literal::List(Rc::new(RefCell::new(names))))
+        }),
+    ];
+
+    let mut environment = Environment::new();
+    let mut exports = Vec::new();
+    for (name, arity, function) in functions {
+        let native = NativeFunction::new(name, arity, move |interpreter: &mut Interpreter, arguments| {
+            function(interpreter.file_access(), &arguments)
+        });
+        environment.define(name, Literal::Callable(Rc::new(native)));
+        exports.push(name.to_string());
+    }
+    Namespace::new("files", Rc::new(RefCell::new(environment)), exports)
+}
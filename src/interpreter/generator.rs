@@ -0,0 +1,337 @@
+use std::{cell::RefCell, collections::VecDeque, fmt, rc::Rc};
+
+use crate::{
+    lexer::{Literal, Token},
+    parser::{expr::Expr, stmt::Stmt},
+};
+
+use super::{environment::Environment, interpret_error::InterpretError, interpreter::Unwind, Interpreter};
+
+// Whether running `stmt` may reach a `yield`; nested function declarations have their own bodies
+pub fn contains_yield(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Yield(_, _) => true,
+        Stmt::Block(statements) => statements.iter().any(|stmt| contains_yield(stmt)),
+        Stmt::If(_, then_branch, else_branch) => {
+            contains_yield(then_branch) || else_branch.as_deref().is_some_and(contains_yield)
+        }
+        Stmt::While(_, body, _) | Stmt::DoWhile(body, _) | Stmt::Loop(body) | Stmt::ForIn(_, _, body) => {
+            contains_yield(body)
+        }
+        Stmt::Match(_, _, arms) => arms.iter().any(|arm| contains_yield(&arm.body)),
+        Stmt::Try(body, _, catch_body, finally_body) => body
+            .iter()
+            .chain(catch_body.iter().flatten())
+            .chain(finally_body.iter().flatten())
+            .any(|stmt| contains_yield(stmt)),
+        _ => false,
+    }
+}
+
+// Where a paused generator is inside its body, innermost last
+enum Frame {
+    // statements of a block, index of the next one, scope to go back to when the block ends
+    Block {
+        statements: Vec<Box<Stmt>>,
+        next: usize,
+        enclosing: Rc<RefCell<Environment>>,
+    },
+    // `while`, `do ... while` and `loop`; a do-while skips the check before its first run
+    Loop {
+        condition: Box<Expr>,
+        body: Box<Stmt>,
+        increment: Option<Box<Expr>>,
+        entered: bool,
+        check_first: bool,
+    },
+    ForIn {
+        name: Token,
+        source: Source,
+        body: Box<Stmt>,
+    },
+}
+
+// The items a paused `for ... in` still has to walk
+enum Source {
+    Items(VecDeque<Literal>),
+    Generator(Rc<Generator>),
+}
+
+// What to do with the innermost frame once it has been looked at
+enum Action {
+    Run(Box<Stmt>),
+    Enter(Vec<Box<Stmt>>, Rc<RefCell<Environment>>),
+    Leave(Option<Rc<RefCell<Environment>>>),
+}
+
+struct State {
+    frames: Vec<Frame>,
+    // The scope that was current when the generator last paused
+    environment: Rc<RefCell<Environment>>,
+    finished: bool,
+}
+
+// The value a generator function returns; its body runs a piece at a time, up to each `yield`
+pub struct Generator {
+    name: String,
+    state: RefCell<State>,
+}
+
+impl Generator {
+    pub fn new(name: &str, body: Vec<Box<Stmt>>, environment: Environment) -> Self {
+        let environment = Rc::new(RefCell::new(environment));
+        Generator {
+            name: name.to_string(),
+            state: RefCell::new(State {
+                frames: vec![Frame::Block {
+                    statements: body,
+                    next: 0,
+                    enclosing: Rc::clone(&environment),
+                }],
+                environment,
+                finished: false,
+            }),
+        }
+    }
+
+    // Runs the body to its next `yield`: `Some(Some(value))` for a yielded value, `Some(None)` once
+    // the body has finished and `None` when it raised an error
+    pub fn resume(&self, interpreter: &mut Interpreter) -> Option<Option<Literal>> {
+        let Ok(mut state) = self.state.try_borrow_mut() else {
+            interpreter.report_error(InterpretError::UnexpectedError(format!(
+                "Generator '{}' is already running",
+                self.name
+            )));
+            return None;
+        };
+
+        if state.finished {
+            return Some(None);
+        }
+
+        let previous = interpreter.swap_environment(Rc::clone(&state.environment));
+        interpreter.push_call(&self.name);
+        let result = Self::run(&mut state.frames, interpreter);
+        interpreter.pop_call();
+        state.environment = interpreter.swap_environment(previous);
+
+        if !matches!(result, Some(Some(_))) {
+            state.finished = true;
+            state.frames.clear();
+        }
+        result
+    }
+
+    fn run(frames: &mut Vec<Frame>, interpreter: &mut Interpreter) -> Option<Option<Literal>> {
+        loop {
+            match interpreter.take_unwinding() {
+                Some(Unwind::Return(_)) => return Some(None),
+                Some(Unwind::Throw(error)) => {
+                    interpreter.set_unwinding(Unwind::Throw(error));
+                    return None;
+                }
+                Some(Unwind::Break) => Self::unwind_to_loop(frames, interpreter, true),
+                Some(Unwind::Continue) => Self::unwind_to_loop(frames, interpreter, false),
+                None => {}
+            }
+
+            let Some(frame) = frames.last_mut() else {
+                return Some(None);
+            };
+
+            let action = match frame {
+                Frame::Block { statements, next, enclosing } => match statements.get(*next) {
+                    Some(stmt) => {
+                        *next += 1;
+                        Action::Run(stmt.clone())
+                    }
+                    None => Action::Leave(Some(Rc::clone(enclosing))),
+                },
+                Frame::Loop { condition, body, increment, entered, check_first } => {
+                    if *entered {
+                        if let Some(increment) = increment {
+                            interpreter.evaluate(increment)?;
+                        }
+                    }
+
+                    let check = *entered || *check_first;
+                    *entered = true;
+
+                    let finished = check && {
+                        let value = interpreter.evaluate(condition)?;
+                        !interpreter.is_truthy(&value)
+                    };
+                    if finished {
+                        Action::Leave(None)
+                    } else {
+                        Action::Run(body.clone())
+                    }
+                }
+                Frame::ForIn { name, source, body } => {
+                    let item = match source {
+                        Source::Items(items) => items.pop_front(),
+                        Source::Generator(generator) => generator.resume(interpreter)?,
+                    };
+
+                    match item {
+                        Some(item) => {
+                            // Each iteration gets a fresh binding, as in an ordinary `for ... in`
+                            let enclosing = interpreter.get_env();
+                            let mut environment = Environment::new_with_env(Rc::clone(&enclosing));
+                            environment.define(name.lexeme(), item);
+                            interpreter.swap_environment(Rc::new(RefCell::new(environment)));
+                            Action::Enter(vec![body.clone()], enclosing)
+                        }
+                        None => Action::Leave(None),
+                    }
+                }
+            };
+
+            match action {
+                Action::Run(stmt) => {
+                    if let Some(value) = Self::start(frames, interpreter, &stmt)? {
+                        return Some(Some(value));
+                    }
+                }
+                Action::Enter(statements, enclosing) => frames.push(Frame::Block {
+                    statements,
+                    next: 0,
+                    enclosing,
+                }),
+                Action::Leave(enclosing) => {
+                    frames.pop();
+                    if let Some(enclosing) = enclosing {
+                        interpreter.swap_environment(enclosing);
+                    }
+                }
+            }
+        }
+    }
+
+    // Begins running `stmt`: statements that cannot yield run to completion, the rest push frames
+    fn start(frames: &mut Vec<Frame>, interpreter: &mut Interpreter, stmt: &Stmt) -> Option<Option<Literal>> {
+        if !contains_yield(stmt) {
+            interpreter.execute(stmt);
+            return Some(None);
+        }
+
+        match stmt {
+            Stmt::Yield(_keyword, value) => {
+                let value = match value {
+                    Some(value) => interpreter.evaluate(value)?,
+                    None => Literal::NullLiteral,
+                };
+                Some(Some(value))
+            }
+            Stmt::Block(statements) => {
+                let enclosing = interpreter.get_env();
+                let environment = Environment::new_with_env(Rc::clone(&enclosing));
+                interpreter.swap_environment(Rc::new(RefCell::new(environment)));
+                frames.push(Frame::Block {
+                    statements: statements.clone(),
+                    next: 0,
+                    enclosing,
+                });
+                Some(None)
+            }
+            Stmt::If(condition, then_branch, else_branch) => {
+                // Same test as an ordinary `if`
+                if let Literal::BooleanLiteral(true) = interpreter.evaluate(condition)? {
+                    Self::start(frames, interpreter, then_branch)
+                } else if let Some(else_branch) = else_branch {
+                    Self::start(frames, interpreter, else_branch)
+                } else {
+                    Some(None)
+                }
+            }
+            Stmt::While(condition, body, increment) => {
+                frames.push(Frame::Loop {
+                    condition: Box::new(condition.clone()),
+                    body: body.clone(),
+                    increment: increment.clone().map(Box::new),
+                    entered: false,
+                    check_first: true,
+                });
+                Some(None)
+            }
+            Stmt::DoWhile(body, condition) => {
+                frames.push(Frame::Loop {
+                    condition: Box::new(condition.clone()),
+                    body: body.clone(),
+                    increment: None,
+                    entered: false,
+                    check_first: false,
+                });
+                Some(None)
+            }
+            Stmt::Loop(body) => {
+                frames.push(Frame::Loop {
+                    condition: Box::new(Expr::Literal(Literal::BooleanLiteral(true))),
+                    body: body.clone(),
+                    increment: None,
+                    entered: false,
+                    check_first: true,
+                });
+                Some(None)
+            }
+            Stmt::ForIn(name, iterable, body) => {
+                let source = match interpreter.evaluate(iterable)? {
+                    Literal::Generator(generator) => Source::Generator(generator),
+                    collection => Source::Items(interpreter.iterate(&collection)?.into()),
+                };
+                frames.push(Frame::ForIn {
+                    name: name.clone(),
+                    source,
+                    body: body.clone(),
+                });
+                Some(None)
+            }
+            Stmt::Match(_keyword, subject, arms) => {
+                let value = interpreter.evaluate(subject)?;
+                let (index, environment) = interpreter.select_match_arm(&value, arms)?;
+                let enclosing = interpreter.swap_environment(environment);
+                frames.push(Frame::Block {
+                    statements: vec![arms[index].body.clone()],
+                    next: 0,
+                    enclosing,
+                });
+                Some(None)
+            }
+            // A `yield` inside `try` is reported when it is reached
+            _ => {
+                interpreter.execute(stmt);
+                Some(None)
+            }
+        }
+    }
+
+    // Drops the frames inside the innermost loop; `break` drops the loop too
+    fn unwind_to_loop(frames: &mut Vec<Frame>, interpreter: &mut Interpreter, is_break: bool) {
+        while let Some(frame) = frames.pop() {
+            match frame {
+                Frame::Block { enclosing, .. } => {
+                    interpreter.swap_environment(enclosing);
+                }
+                frame => {
+                    if !is_break {
+                        frames.push(frame);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+// The paused body holds its scopes, which may hold the generator itself, so only the name is shown
+impl fmt::Debug for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator").field("name", &self.name).finish()
+    }
+}
+
+impl fmt::Display for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<generator {}>", self.name)
+    }
+}
@@ -1,8 +1,8 @@
 use core::fmt;
 
-use crate::lexer::TokenType;
+use crate::lexer::{Literal, TokenType};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum InterpretError {
     DivisionByZero,
     TypeMismatch(String),
@@ -11,18 +11,112 @@ pub enum InterpretError {
     UndefinedVariable(String),
     UnassignmedVariable(String),
     ArgumentMismatch(String),
+    NonExhaustiveMatch(String),
+    UndefinedProperty(String),
+    ImportError(String),
+    ConstantReassignment(String),
+    PatternMismatch(String),
+    IndexOutOfRange(String),
+    FileError(String),
+    JsonError(String),
+    DateError(String),
+    // A value raised by a `throw` statement
+    Thrown(Literal),
+}
+
+impl InterpretError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            InterpretError::DivisionByZero => "DivisionByZero",
+            InterpretError::TypeMismatch(_) => "TypeMismatch",
+            InterpretError::UnsupportedOperator(_) => "UnsupportedOperator",
+            InterpretError::UnexpectedError(_) => "UnexpectedError",
+            InterpretError::UndefinedVariable(_) => "UndefinedVariable",
+            InterpretError::UnassignmedVariable(_) => "UnassignedVariable",
+            InterpretError::ArgumentMismatch(_) => "ArgumentMismatch",
+            InterpretError::NonExhaustiveMatch(_) => "NonExhaustiveMatch",
+            InterpretError::UndefinedProperty(_) => "UndefinedProperty",
+            InterpretError::ImportError(_) => "ImportError",
+            InterpretError::ConstantReassignment(_) => "ConstantReassignment",
+            InterpretError::PatternMismatch(_) => "PatternMismatch",
+            InterpretError::IndexOutOfRange(_) => "IndexOutOfRange",
+            InterpretError::FileError(_) => "FileError",
+            InterpretError::JsonError(_) => "JsonError",
+            InterpretError::DateError(_) => "DateError",
+            InterpretError::Thrown(_) => "Error",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            InterpretError::DivisionByZero => "Cannot divide by zero".to_string(),
+            InterpretError::UnsupportedOperator(token_type) => format!("Unknown type {:?}", token_type),
+            InterpretError::TypeMismatch(err_str)
+            | InterpretError::UnexpectedError(err_str)
+            | InterpretError::UndefinedVariable(err_str)
+            | InterpretError::UnassignmedVariable(err_str)
+            | InterpretError::ArgumentMismatch(err_str)
+            | InterpretError::NonExhaustiveMatch(err_str)
+            | InterpretError::UndefinedProperty(err_str)
+            | InterpretError::ImportError(err_str)
+            | InterpretError::ConstantReassignment(err_str)
+            | InterpretError::PatternMismatch(err_str)
+            | InterpretError::IndexOutOfRange(err_str)
+            | InterpretError::FileError(err_str)
+            | InterpretError::JsonError(err_str)
+            | InterpretError::DateError(err_str) => err_str.clone(),
+            InterpretError::Thrown(value) => value.to_string(),
+        }
+    }
 }
 
 impl fmt::Display for InterpretError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InterpretError::DivisionByZero => write!(f, "Error: Cannot divide by zero"),
-            InterpretError::TypeMismatch(err_str) => write!(f, "Error: {}", err_str),
-            InterpretError::UnexpectedError(err_str) => write!(f, "Error: {}", err_str),
-            InterpretError::UnsupportedOperator(token_type) => write!(f, "Error: Unknown type {:?}", token_type),
-            InterpretError::UndefinedVariable(err_str) => write!(f, "Error: {}", err_str),
-            InterpretError::UnassignmedVariable(err_str) => write!(f, "Error: {}", err_str),
-            InterpretError::ArgumentMismatch(err_str) => write!(f, "Error: {}", err_str),
+        write!(f, "Error: {}", self.message())
+    }
+}
+
+// A raised error as seen by scripts: the error plus where it happened
+#[derive(Debug)]
+pub struct ErrorValue {
+    error: InterpretError,
+    line: usize,
+    // Innermost call first, as (function name, line it was called from)
+    trace: Vec<(String, usize)>,
+}
+
+impl ErrorValue {
+    pub fn new(error: InterpretError, line: usize, trace: Vec<(String, usize)>) -> Self {
+        ErrorValue { error, line, trace }
+    }
+
+    pub fn error(&self) -> &InterpretError {
+        &self.error
+    }
+
+    pub fn kind(&self) -> &'static str {
+        self.error.kind()
+    }
+
+    pub fn message(&self) -> String {
+        self.error.message()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn stack_trace(&self) -> String {
+        let mut trace = format!("Uncaught {}", self);
+        for (function, line) in &self.trace {
+            trace.push_str(&format!("\n\t    in {} called at line {}", function, line));
         }
+        trace
+    }
+}
+
+impl fmt::Display for ErrorValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} (line {})", self.kind(), self.message(), self.line)
     }
-}
\ No newline at end of file
+}
@@ -1,36 +1,166 @@
-use std::rc::Rc;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use crate::{
-    lexer::{Literal, TokenType},
+    Lexer,
+    Resolver,
+    lexer::{Literal, Token, TokenType},
     parser::{
+        parser::Parser,
         expr::Expr,
-        stmt::Stmt,
+        pattern::Pattern,
+        stmt::{MatchArm, Stmt},
         visitor::{ExprVisitor, StmtVisitor},
     },
 };
 
-use super::{callable::NepLatFunc, environment::Environment, interpret_error::InterpretError};
+use super::{
+    callable::{describe_arity, BoundMethod, Callable, NepLatFunc},
+    environment::Environment,
+    files::FileAccess,
+    interpret_error::{ErrorValue, InterpretError},
+    enumeration::{EnumType, Variant},
+    native::{Arguments, Arity, NativeFunction},
+    prelude,
+    strings,
+    module::Namespace,
+};
+
+// Why statements are being skipped on the way out of the current block
+pub(crate) enum Unwind {
+    Throw(Rc<ErrorValue>),
+    Return(Literal),
+    Break,
+    Continue,
+}
 
 pub struct Interpreter {
     errors: Vec<InterpretError>,
-    environment: Environment,
+    uncaught: Vec<Rc<ErrorValue>>,
+    environment: Rc<RefCell<Environment>>,
+    // Built-in functions, enclosing the top level of the script and of every module
+    globals: Rc<RefCell<Environment>>,
+    unwinding: Option<Unwind>,
+    // Functions currently running, as (name, line they were called from)
+    call_stack: Vec<(String, usize)>,
+    line: usize,
+    // The file being run, which imports are resolved against
+    script_path: Option<PathBuf>,
+    // Loaded modules by canonical path, so each file runs only once
+    modules: HashMap<PathBuf, Rc<Namespace>>,
+    // Files whose top level is running right now, outermost first
+    loading: Vec<PathBuf>,
+    // Where `input` reads lines from; stdin unless replaced with `set_input`
+    input: Box<dyn BufRead>,
+    // The directory the `files` module may use; none unless given with `set_file_access`
+    file_access: FileAccess,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Interpreter {
     pub fn new() -> Self {
-        Interpreter {
+        let globals = Rc::new(RefCell::new(Environment::new()));
+
+        let mut interpreter = Interpreter {
             errors: Vec::new(),
-            environment: Environment::new(),
+            uncaught: Vec::new(),
+            environment: Rc::new(RefCell::new(Environment::new_with_env(Rc::clone(&globals)))),
+            globals,
+            unwinding: None,
+            call_stack: Vec::new(),
+            line: 1,
+            script_path: None,
+            modules: HashMap::new(),
+            loading: Vec::new(),
+            input: Box::new(BufReader::new(io::stdin())),
+            file_access: FileAccess::denied(),
+        };
+        prelude::register(&mut interpreter);
+        interpreter
+    }
+
+    // Makes a Rust function callable from scripts as `name`, in every module; a script's own
+    // declaration of the same name shadows it
+    pub fn register_native(
+        &mut self,
+        name: &str,
+        arity: impl Into<Arity>,
+        function: impl Fn(&mut Interpreter, Arguments) -> Result<Literal, InterpretError> + 'static,
+    ) {
+        let function = NativeFunction::new(name, arity, function);
+        self.globals.borrow_mut().define(name, Literal::Callable(Rc::new(function)));
+    }
+
+    // Makes `value` visible to scripts as `name`, in every module, like `register_native` does for functions
+    pub fn register_global(&mut self, name: &str, value: Literal) {
+        self.globals.borrow_mut().define(name, value);
+    }
+
+    // Sets the file the interpreter is running, so its imports resolve relative to it
+    pub fn set_script_path(&mut self, path: &Path) {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.loading = vec![path.clone()];
+        self.script_path = Some(path);
+    }
+
+    // Makes `input` read from `reader` instead of stdin, e.g. scripted answers in tests
+    pub fn set_input(&mut self, reader: impl BufRead + 'static) {
+        self.input = Box::new(reader);
+    }
+
+    // Lets scripts use the files under a directory, see `FileAccess::new`
+    pub fn set_file_access(&mut self, access: FileAccess) {
+        self.file_access = access;
+    }
+
+    pub(crate) fn file_access(&self) -> &FileAccess {
+        &self.file_access
+    }
+
+    // Shows `prompt` and reads one line without its line ending; `None` at the end of the input
+    pub(crate) fn read_line(&mut self, prompt: Option<&str>) -> io::Result<Option<String>> {
+        if let Some(prompt) = prompt {
+            print!("{}", prompt);
+            io::stdout().flush()?;
+        }
+
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Ok(None);
         }
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+        Ok(Some(line))
     }
 
-    pub fn get_env(&self) -> Environment {
-        self.environment.clone()
+    pub fn get_env(&self) -> Rc<RefCell<Environment>> {
+        Rc::clone(&self.environment)
     }
 
     pub fn interpret(&mut self, statements: &[Stmt]) -> Result<(), &Vec<InterpretError>> {
         for stmt in statements {
             self.execute(stmt);
+
+            // An error nobody caught ends its statement; the script moves on to the next one
+            if let Some(Unwind::Throw(error)) = self.unwinding.take() {
+                self.errors.push(error.error().clone());
+                self.uncaught.push(error);
+            }
         }
 
         if !self.errors.is_empty() {
@@ -40,41 +170,1080 @@ impl Interpreter {
         }
     }
 
-    fn execute(&mut self, stmt: &Stmt) {
+    pub(crate) fn execute(&mut self, stmt: &Stmt) {
+        if self.unwinding.is_some() {
+            return;
+        }
+
         stmt.accept(self);
     }
 
     pub fn execute_block(&mut self, statements: Vec<Box<Stmt>>, new_env: Environment) {
-        let _ = std::mem::replace(&mut self.environment, new_env);
+        let previous = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(new_env)));
         for stmt in statements {
             self.execute(&stmt);
         }
 
-        if let Some(enclosed) = self.environment.enclosing.clone() {
-            self.environment = *enclosed;
+        self.environment = previous;
+    }
+
+    // Runs a function body, turning a `return` into the call's value
+    pub(crate) fn call_function(&mut self, name: &str, body: Vec<Box<Stmt>>, environment: Environment) -> Option<Literal> {
+        self.push_call(name);
+        self.execute_block(body, environment);
+        self.pop_call();
+
+        match self.unwinding.take() {
+            Some(Unwind::Return(value)) => Some(value),
+            Some(unwind) => {
+                self.unwinding = Some(unwind);
+                None
+            }
+            None => Some(Literal::NullLiteral),
         }
     }
 
-    fn evaluate(&mut self, expr: &Expr) -> Option<Literal> {
-        expr.accept(self)
+    pub(crate) fn evaluate(&mut self, expr: &Expr) -> Option<Literal> {
+        let previous_line = self.line;
+        if let Some(line) = expr.line() {
+            self.line = line;
+        }
+
+        let value = expr.accept(self);
+        self.line = previous_line;
+        value
     }
 
-    pub fn get_variable(&self, name: &str) -> Option<&Literal> {
-        match self.environment.get(name) {
-            Ok(value) => Some(value),
-            Err(_) => None,
+    // Like `evaluate`, but a variable holding null yields null instead of an unassigned error
+    fn evaluate_nullable(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Variable(token) = expr {
+            let value = self.environment.borrow().get(token.lexeme());
+            return match value {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    self.report_error(error);
+                    None
+                }
+            };
         }
+
+        self.evaluate(expr)
+    }
+
+    pub fn get_variable(&self, name: &str) -> Option<Literal> {
+        self.environment.borrow().get(name).ok()
     }
 
     pub fn get_errors(&self) -> &[InterpretError] {
         &self.errors
     }
 
-    fn report_error(&mut self, error: InterpretError) {
-        self.errors.push(error);
+    pub fn get_uncaught_errors(&self) -> &[Rc<ErrorValue>] {
+        &self.uncaught
+    }
+
+    // Makes `environment` the current scope and hands back the one it replaced
+    pub(crate) fn swap_environment(&mut self, environment: Rc<RefCell<Environment>>) -> Rc<RefCell<Environment>> {
+        std::mem::replace(&mut self.environment, environment)
+    }
+
+    pub(crate) fn take_unwinding(&mut self) -> Option<Unwind> {
+        self.unwinding.take()
+    }
+
+    pub(crate) fn set_unwinding(&mut self, unwind: Unwind) {
+        self.unwinding = Some(unwind);
+    }
+
+    // Records a running function for stack traces
+    pub(crate) fn push_call(&mut self, name: &str) {
+        self.call_stack.push((name.to_string(), self.line));
+    }
+
+    pub(crate) fn pop_call(&mut self) {
+        self.call_stack.pop();
+    }
+
+    // Raises a runtime error at the current line; it unwinds until a `catch` handles it
+    pub(crate) fn report_error(&mut self, error: InterpretError) {
+        let trace = self.call_stack.iter().rev().cloned().collect();
+        self.throw(Rc::new(ErrorValue::new(error, self.line, trace)));
+    }
+
+    fn throw(&mut self, error: Rc<ErrorValue>) {
+        // Keep the first error if another one is reported while already unwinding
+        if self.unwinding.is_none() {
+            self.unwinding = Some(Unwind::Throw(error));
+        }
+    }
+
+    fn apply_binary_operator(&mut self, operator: &TokenType, left_value: Literal, right_value: Literal) -> Option<Literal> {
+        match operator {
+            // Handle +
+            TokenType::PLUS => match (left_value, right_value) {
+                // Handle numeric addition
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::NumberLiteral(left_num + right_num))
+                }
+                // Handle string concatenation with +
+                (Literal::StringLiteral(left_str), Literal::StringLiteral(right_str)) => {
+                    Some(Literal::StringLiteral(left_str + &right_str))
+                }
+                // Handle string + number or number + string
+                (Literal::StringLiteral(left_str), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::StringLiteral(left_str + &right_num.to_string()))
+                }
+                (Literal::NumberLiteral(left_num), Literal::StringLiteral(right_str)) => {
+                    Some(Literal::StringLiteral(left_num.to_string() + &right_str))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in addition".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle -
+            TokenType::MINUS => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::NumberLiteral(left_num - right_num))
+                }
+                // Set difference: the items of the left set missing from the right one
+                (Literal::Set(left_items), Literal::Set(right_items)) => {
+                    let items = left_items
+                        .iter()
+                        .filter(|item| !right_items.iter().any(|other| self.is_equal(item, other)))
+                        .cloned()
+                        .collect();
+                    Some(Literal::new_set(items))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in subtraction".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle *
+            TokenType::STAR => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::NumberLiteral(left_num * right_num))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in multiplication".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle /
+            TokenType::SLASH => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    if right_num == 0.0 {
+                        // Handle division by zero case
+                        self.report_error(InterpretError::DivisionByZero);
+                        None
+                    } else {
+                        Some(Literal::NumberLiteral(left_num / right_num))
+                    }
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in division".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle >
+            TokenType::GREATER => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::BooleanLiteral(left_num > right_num))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in comparison".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle >=
+            TokenType::GREATEREQUAL => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::BooleanLiteral(left_num >= right_num))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in comparison".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle <
+            TokenType::LESS => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::BooleanLiteral(left_num < right_num))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in comparison".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle <=
+            TokenType::LESSEQUAL => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    Some(Literal::BooleanLiteral(left_num <= right_num))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in comparison".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle | (set union)
+            TokenType::PIPE => match (left_value, right_value) {
+                (Literal::Set(left_items), Literal::Set(right_items)) => {
+                    let mut items = left_items.as_ref().clone();
+                    for item in right_items.iter() {
+                        self.set_insert(&mut items, item.clone());
+                    }
+                    Some(Literal::new_set(items))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in union; both sides must be sets".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle & (set intersection)
+            TokenType::AMPERSAND => match (left_value, right_value) {
+                (Literal::Set(left_items), Literal::Set(right_items)) => {
+                    let items = left_items
+                        .iter()
+                        .filter(|item| right_items.iter().any(|other| self.is_equal(item, other)))
+                        .cloned()
+                        .collect();
+                    Some(Literal::new_set(items))
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in intersection; both sides must be sets".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            // Handle `in` (membership)
+            TokenType::IN => self.contains(&right_value, &left_value).map(Literal::BooleanLiteral),
+
+            // Handle ==
+            TokenType::EQUALEQUAL => Some(Literal::BooleanLiteral(self.is_equal(&left_value, &right_value))),
+
+            // Handle !=
+            TokenType::BANGEQUAL => Some(Literal::BooleanLiteral(!self.is_equal(&left_value, &right_value))),
+
+            // Handle %
+            TokenType::PERCENT => match (left_value, right_value) {
+                (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
+                    if right_num == 0.0 {
+                        self.report_error(InterpretError::DivisionByZero);
+                        None
+                    } else {
+                        Some(Literal::NumberLiteral(left_num % right_num))
+                    }
+                }
+                _ => {
+                    self.report_error(InterpretError::TypeMismatch(
+                        "Type mismatch in modulo".to_string(),
+                    ));
+                    None
+                }
+            },
+
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_equal(&self, left: &Literal, right: &Literal) -> bool {
+        match (left, right) {
+            // Case: Number equality check
+            (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => left_num == right_num,
+
+            // Case: String equality check
+            (Literal::StringLiteral(left_str), Literal::StringLiteral(right_str)) => left_str == right_str,
+
+            // Case: Both are null (null equality)
+            (Literal::NullLiteral, Literal::NullLiteral) => true,
+
+            //Case: Both are boolean
+            (Literal::BooleanLiteral(left), Literal::BooleanLiteral(right)) => left == right,
+
+            // Case: Lists are equal when their elements are pairwise equal
+            (Literal::List(left_items), Literal::List(right_items)) => {
+                let left_items = left_items.borrow();
+                let right_items = right_items.borrow();
+                left_items.len() == right_items.len()
+                    && left_items
+                        .iter()
+                        .zip(right_items.iter())
+                        .all(|(left, right)| self.is_equal(left, right))
+            }
+
+            // Case: Tuples are equal when their elements are pairwise equal
+            (Literal::Tuple(left_items), Literal::Tuple(right_items)) => {
+                left_items.len() == right_items.len()
+                    && left_items
+                        .iter()
+                        .zip(right_items.iter())
+                        .all(|(left, right)| self.is_equal(left, right))
+            }
+
+            // Case: Maps are equal when they hold equal values under the same keys, in any order
+            (Literal::Map(left_entries), Literal::Map(right_entries)) => {
+                let left_entries = left_entries.borrow();
+                let right_entries = right_entries.borrow();
+                left_entries.len() == right_entries.len()
+                    && left_entries.iter().all(|(key, left)| {
+                        self.map_get(&right_entries, key)
+                            .is_some_and(|right| self.is_equal(left, &right))
+                    })
+            }
+
+            // Case: Sets are equal when they hold the same items, in any order
+            (Literal::Set(left_items), Literal::Set(right_items)) => {
+                left_items.len() == right_items.len()
+                    && left_items
+                        .iter()
+                        .all(|left| right_items.iter().any(|right| self.is_equal(left, right)))
+            }
+
+            // Case: The same enum declaration
+            (Literal::Enum(left_type), Literal::Enum(right_type)) => Rc::ptr_eq(left_type, right_type),
+
+            // Case: Variants are equal when they are the same variant holding equal data
+            (Literal::Variant(left_variant), Literal::Variant(right_variant)) => {
+                left_variant.same_variant(right_variant)
+                    && left_variant
+                        .values()
+                        .iter()
+                        .zip(right_variant.values())
+                        .all(|(left, right)| self.is_equal(left, right))
+            }
+
+            // For type mismatches or unsupported types
+            _ => false,
+        }
+    }
+
+    // Finds the value stored under `key`, comparing keys the way `==` does
+    fn map_get(&self, entries: &[(Literal, Literal)], key: &Literal) -> Option<Literal> {
+        entries
+            .iter()
+            .find(|(entry_key, _)| self.is_equal(entry_key, key))
+            .map(|(_, value)| value.clone())
+    }
+
+    // Stores `value` under `key`, replacing an equal key in place
+    fn map_insert(&self, entries: &mut Vec<(Literal, Literal)>, key: Literal, value: Literal) {
+        match entries.iter_mut().find(|(entry_key, _)| self.is_equal(entry_key, &key)) {
+            Some(entry) => entry.1 = value,
+            None => entries.push((key, value)),
+        }
+    }
+
+    // Adds `item` unless an equal one is already there
+    fn set_insert(&self, items: &mut Vec<Literal>, item: Literal) {
+        if !items.iter().any(|existing| self.is_equal(existing, &item)) {
+            items.push(item);
+        }
+    }
+
+    // Whether `collection` holds `item`: an element of a list, tuple or set, a key of a map or a substring
+    fn contains(&mut self, collection: &Literal, item: &Literal) -> Option<bool> {
+        match (collection, item) {
+            (Literal::List(items), _) => Some(items.borrow().iter().any(|other| self.is_equal(other, item))),
+            (Literal::Tuple(items), _) | (Literal::Set(items), _) => {
+                Some(items.iter().any(|other| self.is_equal(other, item)))
+            }
+            (Literal::Map(entries), _) => Some(self.map_get(&entries.borrow(), item).is_some()),
+            (Literal::StringLiteral(text), Literal::StringLiteral(part)) => Some(text.contains(part.as_str())),
+            _ => {
+                self.report_error(InterpretError::TypeMismatch(format!(
+                    "Cannot look for a {} in a {}",
+                    item.type_name(),
+                    collection.type_name()
+                )));
+                None
+            }
+        }
+    }
+
+    // The items a `for ... in` loop or a `...` spread walks over; maps give their keys
+    pub(crate) fn iterate(&mut self, collection: &Literal) -> Option<Vec<Literal>> {
+        match collection {
+            // Runs the generator to the end, so an endless one never returns
+            Literal::Generator(generator) => {
+                let mut items = Vec::new();
+                while let Some(item) = generator.resume(self)? {
+                    items.push(item);
+                }
+                Some(items)
+            }
+            Literal::List(items) => Some(items.borrow().clone()),
+            Literal::Tuple(items) | Literal::Set(items) => Some(items.as_ref().clone()),
+            Literal::Map(entries) => Some(entries.borrow().iter().map(|(key, _)| key.clone()).collect()),
+            Literal::StringLiteral(text) => Some(
+                text.chars()
+                    .map(|c| Literal::StringLiteral(c.to_string()))
+                    .collect(),
+            ),
+            _ => {
+                self.report_error(InterpretError::TypeMismatch(format!(
+                    "Cannot iterate over a {}",
+                    collection.type_name()
+                )));
+                None
+            }
+        }
+    }
+
+    // Only values that can never change may be map keys, so a stored key cannot go stale
+    fn is_hashable(&self, value: &Literal) -> bool {
+        match value {
+            Literal::StringLiteral(_) | Literal::NumberLiteral(_) | Literal::BooleanLiteral(_) | Literal::NullLiteral => true,
+            Literal::Tuple(items) => items.iter().all(|item| self.is_hashable(item)),
+            Literal::Variant(variant) => variant.values().iter().all(|item| self.is_hashable(item)),
+            _ => false,
+        }
+    }
+
+    // `role` names what the value is used as, e.g. "map key"
+    fn check_hashable(&mut self, key: &Literal, role: &str) -> bool {
+        if self.is_hashable(key) {
+            return true;
+        }
+
+        self.report_error(InterpretError::TypeMismatch(format!(
+            "A {} cannot be a {}; use a string, number, boolean, null or a tuple of those",
+            key.type_name(),
+            role
+        )));
+        false
+    }
+
+    // Turns an index value into a position in a sequence of `length` items; negative indexes count from the end
+    fn sequence_index(&mut self, index: &Literal, length: usize) -> Option<usize> {
+        let number = match index {
+            Literal::NumberLiteral(number) if number.fract() == 0.0 => *number,
+            other => {
+                self.report_error(InterpretError::TypeMismatch(format!(
+                    "Index must be a whole number, found {}",
+                    other
+                )));
+                return None;
+            }
+        };
+
+        let position = if number < 0.0 { length as f64 + number } else { number };
+        if position < 0.0 || position >= length as f64 {
+            self.report_error(InterpretError::IndexOutOfRange(format!(
+                "Index {} is out of range for length {}",
+                number, length
+            )));
+            return None;
+        }
+
+        Some(position as usize)
+    }
+
+    // `object[index]` for lists, tuples, strings and maps
+    fn read_element(&mut self, object: &Literal, index: &Literal) -> Option<Literal> {
+        match object {
+            Literal::List(items) => {
+                let items = items.borrow();
+                let position = self.sequence_index(index, items.len())?;
+                Some(items[position].clone())
+            }
+            Literal::Tuple(items) => {
+                let position = self.sequence_index(index, items.len())?;
+                Some(items[position].clone())
+            }
+            Literal::StringLiteral(text) => {
+                let characters: Vec<char> = text.chars().collect();
+                let position = self.sequence_index(index, characters.len())?;
+                Some(Literal::StringLiteral(characters[position].to_string()))
+            }
+            Literal::Map(entries) => {
+                if !self.check_hashable(index, "map key") {
+                    return None;
+                }
+
+                let value = self.map_get(&entries.borrow(), index);
+                if value.is_none() {
+                    self.report_error(InterpretError::UndefinedProperty(format!(
+                        "Map has no key {}",
+                        index
+                    )));
+                }
+                value
+            }
+            other => {
+                self.report_error(InterpretError::TypeMismatch(format!(
+                    "Cannot index into a {}",
+                    other.type_name()
+                )));
+                None
+            }
+        }
+    }
+
+    // `object[index] = value` for lists and maps; yields the value stored
+    fn write_element(&mut self, object: &Literal, index: Literal, value: Literal) -> Option<Literal> {
+        match object {
+            Literal::List(items) => {
+                let length = items.borrow().len();
+                let position = self.sequence_index(&index, length)?;
+                items.borrow_mut()[position] = value.clone();
+                Some(value)
+            }
+            Literal::Map(entries) => {
+                if !self.check_hashable(&index, "map key") {
+                    return None;
+                }
+
+                self.map_insert(&mut entries.borrow_mut(), index, value.clone());
+                Some(value)
+            }
+            Literal::Tuple(_) => {
+                self.report_error(InterpretError::TypeMismatch(
+                    "Tuples are immutable; build a new tuple instead".to_string(),
+                ));
+                None
+            }
+            other => {
+                self.report_error(InterpretError::TypeMismatch(format!(
+                    "Cannot assign into a {}",
+                    other.type_name()
+                )));
+                None
+            }
+        }
+    }
+
+    // The binary operator behind a compound assignment, e.g. `+` for `+=`
+    fn compound_operator(&mut self, operator: &Token) -> Option<TokenType> {
+        match operator.token_type() {
+            TokenType::PLUSEQUAL => Some(TokenType::PLUS),
+            TokenType::MINUSEQUAL => Some(TokenType::MINUS),
+            TokenType::STAREQUAL => Some(TokenType::STAR),
+            TokenType::SLASHEQUAL => Some(TokenType::SLASH),
+            TokenType::PERCENTEQUAL => Some(TokenType::PERCENT),
+            other => {
+                self.report_error(InterpretError::UnsupportedOperator(other.clone()));
+                None
+            }
+        }
+    }
+
+    // The value before and after a `++` or `--`
+    fn step_number(&mut self, operator: &Token, current: Literal) -> Option<(f64, f64)> {
+        let current = match current {
+            Literal::NumberLiteral(num) => num,
+            _ => {
+                self.report_error(InterpretError::TypeMismatch(format!(
+                    "Operand of '{}' must be a number",
+                    operator.lexeme()
+                )));
+                return None;
+            }
+        };
+
+        let updated = match operator.token_type() {
+            TokenType::PLUSPLUS => current + 1.0,
+            _ => current - 1.0,
+        };
+        Some((current, updated))
+    }
+
+    // Checks `value` against `pattern`, collecting any names it binds
+    fn match_pattern(&self, pattern: &Pattern, value: &Literal, bindings: &mut Vec<(String, Literal)>) -> bool {
+        match pattern {
+            Pattern::Wildcard => true,
+            Pattern::Literal(literal) => self.is_equal(literal, value),
+            Pattern::Range(start, end, inclusive) => match value {
+                Literal::NumberLiteral(num) => {
+                    *num >= *start && if *inclusive { *num <= *end } else { *num < *end }
+                }
+                _ => false,
+            },
+            Pattern::Binding(name) => {
+                bindings.push((name.lexeme().to_string(), value.clone()));
+                true
+            }
+            Pattern::List(elements, rest) => {
+                let items = match value {
+                    Literal::List(items) => items.borrow().clone(),
+                    _ => return false,
+                };
+
+                let length_matches = match rest {
+                    Some(_) => items.len() >= elements.len(),
+                    None => items.len() == elements.len(),
+                };
+                if !length_matches {
+                    return false;
+                }
+
+                for (element, item) in elements.iter().zip(items.iter()) {
+                    if !self.match_pattern(element, item, bindings) {
+                        return false;
+                    }
+                }
+
+                match rest {
+                    Some(rest) => {
+                        let remaining = Literal::new_list(items[elements.len()..].to_vec());
+                        self.match_pattern(rest, &remaining, bindings)
+                    }
+                    None => true,
+                }
+            }
+            Pattern::Tuple(elements) => match value {
+                Literal::Tuple(items) => {
+                    items.len() == elements.len()
+                        && elements
+                            .iter()
+                            .zip(items.iter())
+                            .all(|(element, item)| self.match_pattern(element, item, bindings))
+                }
+                _ => false,
+            },
+            Pattern::Map(entries) => {
+                let map = match value {
+                    Literal::Map(map) => map.borrow().clone(),
+                    _ => return false,
+                };
+
+                entries.iter().all(|(key, pattern)| {
+                    match self.map_get(&map, &Literal::StringLiteral(key.lexeme().to_string())) {
+                        Some(item) => self.match_pattern(pattern, &item, bindings),
+                        None => false,
+                    }
+                })
+            }
+            Pattern::Variant(path, name, fields) => match value {
+                Literal::Variant(variant) if self.is_variant(path, name, variant) => match fields {
+                    Some(fields) => {
+                        fields.len() == variant.values().len()
+                            && fields
+                                .iter()
+                                .zip(variant.values())
+                                .all(|(field, item)| self.match_pattern(field, item, bindings))
+                    }
+                    None => true,
+                },
+                _ => false,
+            },
+        }
+    }
+
+    // Whether `variant` is the one a pattern names, looking the enum up by its path at the time of the match
+    fn is_variant(&self, path: &[Token], name: &Token, variant: &Variant) -> bool {
+        let Some((first, rest)) = path.split_first() else {
+            return false;
+        };
+        let Ok(mut target) = self.environment.borrow().get(first.lexeme()) else {
+            return false;
+        };
+
+        for segment in rest {
+            target = match &target {
+                Literal::Namespace(namespace) => match namespace.get(segment.lexeme()) {
+                    Some(member) => member,
+                    None => return false,
+                },
+                _ => return false,
+            };
+        }
+
+        match target {
+            Literal::Enum(enum_type) => {
+                Rc::ptr_eq(&enum_type, variant.enum_type()) && variant.name() == name.lexeme()
+            }
+            _ => false,
+        }
+    }
+
+    // Like `match_pattern`, but explains the first place where the value does not fit the pattern
+    fn destructure(&mut self, pattern: &Pattern, value: &Literal, bindings: &mut Vec<(Token, Literal)>) -> bool {
+        match pattern {
+            Pattern::Wildcard => true,
+            Pattern::Binding(name) => {
+                bindings.push((name.clone(), value.clone()));
+                true
+            }
+            Pattern::List(elements, rest) => {
+                let items = match value {
+                    Literal::List(items) => items.borrow().clone(),
+                    other => {
+                        self.report_error(InterpretError::PatternMismatch(format!(
+                            "Cannot destructure a {} as a list",
+                            other.type_name()
+                        )));
+                        return false;
+                    }
+                };
+
+                let length_matches = match rest {
+                    Some(_) => items.len() >= elements.len(),
+                    None => items.len() == elements.len(),
+                };
+                if !length_matches {
+                    self.report_error(InterpretError::PatternMismatch(format!(
+                        "Expected {}{} elements but the list has {}",
+                        if rest.is_some() { "at least " } else { "" },
+                        elements.len(),
+                        items.len()
+                    )));
+                    return false;
+                }
+
+                for (element, item) in elements.iter().zip(items.iter()) {
+                    if !self.destructure(element, item, bindings) {
+                        return false;
+                    }
+                }
+
+                match rest {
+                    Some(rest) => {
+                        let remaining = Literal::new_list(items[elements.len()..].to_vec());
+                        self.destructure(rest, &remaining, bindings)
+                    }
+                    None => true,
+                }
+            }
+            Pattern::Tuple(elements) => {
+                let items = match value {
+                    Literal::Tuple(items) => Rc::clone(items),
+                    other => {
+                        self.report_error(InterpretError::PatternMismatch(format!(
+                            "Cannot destructure a {} as a tuple",
+                            other.type_name()
+                        )));
+                        return false;
+                    }
+                };
+
+                if items.len() != elements.len() {
+                    self.report_error(InterpretError::PatternMismatch(format!(
+                        "Expected {} elements but the tuple has {}",
+                        elements.len(),
+                        items.len()
+                    )));
+                    return false;
+                }
+
+                elements
+                    .iter()
+                    .zip(items.iter())
+                    .all(|(element, item)| self.destructure(element, item, bindings))
+            }
+            Pattern::Map(entries) => {
+                let map = match value {
+                    Literal::Map(map) => map.borrow().clone(),
+                    other => {
+                        self.report_error(InterpretError::PatternMismatch(format!(
+                            "Cannot destructure a {} as a map",
+                            other.type_name()
+                        )));
+                        return false;
+                    }
+                };
+
+                for (key, pattern) in entries {
+                    match self.map_get(&map, &Literal::StringLiteral(key.lexeme().to_string())) {
+                        Some(item) => {
+                            if !self.destructure(pattern, &item, bindings) {
+                                return false;
+                            }
+                        }
+                        None => {
+                            self.report_error(InterpretError::PatternMismatch(format!(
+                                "Map has no key '{}'",
+                                key.lexeme()
+                            )));
+                            return false;
+                        }
+                    }
+                }
+
+                true
+            }
+            Pattern::Variant(path, name, Some(fields)) => {
+                let variant = match value {
+                    Literal::Variant(variant) if self.is_variant(path, name, variant) => Rc::clone(variant),
+                    other => {
+                        self.report_error(InterpretError::PatternMismatch(format!(
+                            "Expected {} but found {}",
+                            pattern, other
+                        )));
+                        return false;
+                    }
+                };
+
+                if fields.len() != variant.values().len() {
+                    self.report_error(InterpretError::PatternMismatch(format!(
+                        "Expected {} fields but {} has {}",
+                        fields.len(),
+                        variant,
+                        variant.values().len()
+                    )));
+                    return false;
+                }
+
+                fields
+                    .iter()
+                    .zip(variant.values())
+                    .all(|(field, item)| self.destructure(field, item, bindings))
+            }
+            Pattern::Literal(_) | Pattern::Range(_, _, _) | Pattern::Variant(_, _, None) => {
+                let mut ignored = Vec::new();
+                if self.match_pattern(pattern, value, &mut ignored) {
+                    return true;
+                }
+
+                self.report_error(InterpretError::PatternMismatch(format!(
+                    "Expected {} but found {}",
+                    pattern, value
+                )));
+                false
+            }
+        }
+    }
+
+    // Finds an imported file next to the importing one; `.neplat` may be left off
+    fn resolve_module_path(&self, path: &str) -> Option<PathBuf> {
+        let base = self
+            .script_path
+            .as_ref()
+            .and_then(|script| script.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let mut candidate = base.join(path);
+        if !candidate.is_file() && candidate.extension().is_none() {
+            candidate.set_extension("neplat");
+        }
+
+        fs::canonicalize(candidate).ok()
+    }
+
+    fn load_module(&mut self, path: &Path, display_path: &str) -> Option<Rc<Namespace>> {
+        if let Some(namespace) = self.modules.get(path) {
+            return Some(Rc::clone(namespace));
+        }
+
+        if let Some(position) = self.loading.iter().position(|loading| loading == path) {
+            let mut cycle: Vec<String> = self.loading[position..]
+                .iter()
+                .map(|loading| loading.display().to_string())
+                .collect();
+            cycle.push(path.display().to_string());
+
+            self.report_error(InterpretError::ImportError(format!(
+                "Circular import: {}",
+                cycle.join(" -> ")
+            )));
+            return None;
+        }
+
+        let source = match fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(error) => {
+                self.report_error(InterpretError::ImportError(format!(
+                    "Cannot read module '{}': {}",
+                    display_path, error
+                )));
+                return None;
+            }
+        };
+
+        let mut lexer = Lexer::new(source.as_bytes());
+        let tokens = lexer.tokenize();
+        if let Some(error) = lexer.get_errors().first() {
+            self.report_error(InterpretError::ImportError(format!(
+                "Module '{}' failed to lex: {}",
+                display_path, error
+            )));
+            return None;
+        }
+
+        let mut parser = Parser::new(&tokens);
+        let statements = parser.parse();
+        if let Some(error) = parser.get_errors().first() {
+            self.report_error(InterpretError::ImportError(format!(
+                "Module '{}' failed to parse: {}",
+                display_path, error
+            )));
+            return None;
+        }
+
+        let mut resolver = Resolver::new();
+        resolver.resolve(&statements);
+        if let Some(error) = resolver.get_errors().first() {
+            self.report_error(InterpretError::ImportError(format!(
+                "Module '{}' failed to resolve: {}",
+                display_path, error
+            )));
+            return None;
+        }
+
+        // A module runs in its own environment, with its own file as the base for nested imports
+        let module_env = Rc::new(RefCell::new(Environment::new_with_env(Rc::clone(&self.globals))));
+        let previous_env = std::mem::replace(&mut self.environment, Rc::clone(&module_env));
+        let previous_path = self.script_path.replace(path.to_path_buf());
+        self.loading.push(path.to_path_buf());
+
+        for stmt in &statements {
+            self.execute(stmt);
+        }
+
+        self.loading.pop();
+        self.script_path = previous_path;
+        self.environment = previous_env;
+
+        // A top-level `return` just ends the module early; only an error fails the import
+        if let Some(Unwind::Throw(error)) = self.unwinding.take() {
+            self.throw(error);
+            return None;
+        }
+
+        let exports = statements
+            .iter()
+            .flat_map(|stmt| match stmt {
+                Stmt::Export(declaration) => match declaration.as_ref() {
+                    Stmt::Var(name, _)
+                    | Stmt::Const(name, _)
+                    | Stmt::Function(name, _, _)
+                    | Stmt::Enum(name, _) => {
+                        vec![name.lexeme().to_string()]
+                    }
+                    Stmt::Destructure(pattern, _, _) => {
+                        pattern.bindings().iter().map(|name| name.lexeme().to_string()).collect()
+                    }
+                    _ => Vec::new(),
+                },
+                _ => Vec::new(),
+            })
+            .collect();
+
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| display_path.to_string());
+        let namespace = Rc::new(Namespace::new(&name, module_env, exports));
+        self.modules.insert(path.to_path_buf(), Rc::clone(&namespace));
+
+        Some(namespace)
+    }
+
+    // Finds the first arm that fits `value` and whose guard passes, with the scope holding its bindings
+    pub(crate) fn select_match_arm(&mut self, value: &Literal, arms: &[MatchArm]) -> Option<(usize, Rc<RefCell<Environment>>)> {
+        for (index, arm) in arms.iter().enumerate() {
+            let mut bindings: Vec<(String, Literal)> = Vec::new();
+            if !self.match_pattern(&arm.pattern, value, &mut bindings) {
+                continue;
+            }
+
+            // Each arm gets a fresh scope holding the names its pattern bound
+            let mut arm_env = Environment::new_with_env(Rc::clone(&self.environment));
+            for (name, bound) in bindings {
+                arm_env.define(&name, bound);
+            }
+            let arm_env = Rc::new(RefCell::new(arm_env));
+
+            let guard_passed = match &arm.guard {
+                Some(guard) => {
+                    let previous = std::mem::replace(&mut self.environment, Rc::clone(&arm_env));
+                    let result = self.evaluate(guard);
+                    self.environment = previous;
+                    match result {
+                        Some(result) => self.is_truthy(&result),
+                        None => return None,
+                    }
+                }
+                None => true,
+            };
+
+            if guard_passed {
+                return Some((index, arm_env));
+            }
+        }
+
+        self.report_error(InterpretError::NonExhaustiveMatch(format!(
+            "No match arm matched value {}",
+            value
+        )));
+        None
+    }
+
+    // Methods built-in values answer to, bound to the value they were read from (`gen.next`, `text.upper`)
+    fn builtin_method(&self, receiver: &Literal, name: &str) -> Option<Literal> {
+        let method: Rc<dyn Callable> = match (receiver, name) {
+            (Literal::StringLiteral(_), _) => Rc::new(strings::method(name)?),
+            (Literal::Generator(_), "next") => match self.globals.borrow().get("next") {
+                Ok(Literal::Callable(method)) => method,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        let bound = BoundMethod::new(receiver.clone(), method);
+        Some(Literal::Callable(Rc::new(bound)))
+    }
+
+    // Runs one iteration of a `for ... in` and says whether the loop should stop
+    fn run_for_in_body(&mut self, name: &Token, item: Literal, body: &Stmt) -> bool {
+        // Each iteration gets a fresh binding, so closures keep the item they saw
+        let mut environment = Environment::new_with_env(Rc::clone(&self.environment));
+        environment.define(name.lexeme(), item);
+
+        let previous = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(environment)));
+        self.execute(body);
+        self.environment = previous;
+
+        self.loop_should_exit()
+    }
+
+    // Consumes a `break` or `continue` aimed at the running loop and says whether the loop should stop
+    fn loop_should_exit(&mut self) -> bool {
+        match self.unwinding {
+            Some(Unwind::Break) => {
+                self.unwinding = None;
+                true
+            }
+            Some(Unwind::Continue) => {
+                self.unwinding = None;
+                false
+            }
+            Some(_) => true,
+            None => false,
+        }
     }
 
-    fn is_truthy(&self, value: &Literal) -> bool {
+    pub(crate) fn is_truthy(&self, value: &Literal) -> bool {
         match value {
             Literal::BooleanLiteral(b) => *b,
             Literal::NullLiteral => false,
@@ -128,199 +1297,45 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
             let left_value = self.evaluate(left)?;
             let right_value = self.evaluate(right)?;
 
-            match operator.token_type() {
-                // Handle +
-                TokenType::PLUS => match (left_value, right_value) {
-                    // Handle numeric addition
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::NumberLiteral(left_num + right_num))
-                    }
-                    // Handle string concatenation with +
-                    (Literal::StringLiteral(left_str), Literal::StringLiteral(right_str)) => {
-                        Some(Literal::StringLiteral(left_str + &right_str))
-                    }
-                    // Handle string + number or number + string
-                    (Literal::StringLiteral(left_str), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::StringLiteral(left_str + &right_num.to_string()))
-                    }
-                    (Literal::NumberLiteral(left_num), Literal::StringLiteral(right_str)) => {
-                        Some(Literal::StringLiteral(left_num.to_string() + &right_str))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in addition".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle -
-                TokenType::MINUS => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::NumberLiteral(left_num - right_num))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in subtraction".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle *
-                TokenType::STAR => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::NumberLiteral(left_num * right_num))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in multiplication".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle /
-                TokenType::SLASH => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        if right_num == 0.0 {
-                            // Handle division by zero case
-                            self.report_error(InterpretError::DivisionByZero);
-                            None
-                        } else {
-                            Some(Literal::NumberLiteral(left_num / right_num))
-                        }
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in division".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle >
-                TokenType::GREATER => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::BooleanLiteral(left_num > right_num))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in comparison".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle >=
-                TokenType::GREATEREQUAL => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::BooleanLiteral(left_num >= right_num))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in comparison".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle <
-                TokenType::LESS => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::BooleanLiteral(left_num < right_num))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in comparison".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle <=
-                TokenType::LESSEQUAL => match (left_value, right_value) {
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::BooleanLiteral(left_num <= right_num))
-                    }
-                    _ => {
-                        self.report_error(InterpretError::TypeMismatch(
-                            "Type mismatch in comparison".to_string(),
-                        ));
-                        None
-                    }
-                },
-
-                // Handle ==
-                TokenType::EQUALEQUAL => match (left_value, right_value) {
-                    // Case: Number equality check
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::BooleanLiteral(left_num == right_num))
-                    }
-
-                    // Case: String equality check
-                    (Literal::StringLiteral(left_str), Literal::StringLiteral(right_str)) => {
-                        Some(Literal::BooleanLiteral(left_str == right_str))
-                    }
-
-                    // Case: Both are null (null equality)
-                    (Literal::NullLiteral, Literal::NullLiteral) => {
-                        Some(Literal::BooleanLiteral(true))
-                    }
-
-                    //Case: Both are boolean
-                    (Literal::BooleanLiteral(left), Literal::BooleanLiteral(right)) => {
-                        Some(Literal::BooleanLiteral(left == right))
-                    }
-
-                    // For type mismatches or unsupported types
-                    _ => Some(Literal::BooleanLiteral(false)),
-                },
-
-                // Handle !=
-                TokenType::BANGEQUAL => match (left_value, right_value) {
-                    // Case: Number equality check
-                    (Literal::NumberLiteral(left_num), Literal::NumberLiteral(right_num)) => {
-                        Some(Literal::BooleanLiteral(left_num != right_num))
-                    }
-
-                    // Case: String equality check
-                    (Literal::StringLiteral(left_str), Literal::StringLiteral(right_str)) => {
-                        Some(Literal::BooleanLiteral(left_str != right_str))
-                    }
-
-                    // Case: Both are null (null equality)
-                    (Literal::NullLiteral, Literal::NullLiteral) => {
-                        Some(Literal::BooleanLiteral(false))
-                    }
+            self.apply_binary_operator(operator.token_type(), left_value, right_value)
+        } else {
+            None
+        }
+    }
 
-                    //Case: Both are boolean
-                    (Literal::BooleanLiteral(left), Literal::BooleanLiteral(right)) => {
-                        Some(Literal::BooleanLiteral(left != right))
-                    }
+    fn visit_assign_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Assign(token, value_expr) = expr {
+            let value = self.evaluate(value_expr)?;
 
-                    // For type mismatches or unsupported types
-                    _ => Some(Literal::BooleanLiteral(true)),
+            let assigned = self.environment.borrow_mut().assign(token, value.clone());
+            match assigned {
+                Ok(_) => {
+                    Some(value)
                 },
-
-                _ => None,
+                Err(error) => {
+                    self.report_error(error);
+                    None
+                }
             }
         } else {
             None
         }
     }
 
-    fn visit_assign_expression(&mut self, expr: &Expr) -> Option<Literal> {
-        if let Expr::Assign(token, value_expr) = expr {
+    fn visit_compound_assign_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::CompoundAssign(token, operator, value_expr) = expr {
+            let current = self.evaluate(&Expr::Variable(token.clone()))?;
             let value = self.evaluate(value_expr)?;
 
-            match self.environment.assign(token, value.clone()) {
-                Ok(_) => {
-                    return Some(value);
-                },
+            let binary_operator = self.compound_operator(operator)?;
+            let result = self.apply_binary_operator(&binary_operator, current, value)?;
+
+            let assigned = self.environment.borrow_mut().assign(token, result.clone());
+            match assigned {
+                Ok(_) => Some(result),
                 Err(error) => {
                     self.report_error(error);
-                    return None;
+                    None
                 }
             }
         } else {
@@ -328,13 +1343,36 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
         }
     }
 
+    fn visit_update_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Update(token, operator, prefix) = expr {
+            let current = self.evaluate(&Expr::Variable(token.clone()))?;
+            let (current, updated) = self.step_number(operator, current)?;
+
+            let assigned = self.environment.borrow_mut().assign(token, Literal::NumberLiteral(updated));
+            if let Err(error) = assigned {
+                self.report_error(error);
+                return None;
+            }
+
+            // Prefix form yields the new value, postfix form the old one
+            if *prefix {
+                Some(Literal::NumberLiteral(updated))
+            } else {
+                Some(Literal::NumberLiteral(current))
+            }
+        } else {
+            None
+        }
+    }
+
     fn vist_variable_expr(&mut self, expr: &Expr) -> Option<Literal> {
         if let Expr::Variable(token) = expr {
             let token_name = token.lexeme();
-            match self.environment.get(token_name) {
+            let value = self.environment.borrow().get(token_name);
+            match value {
                 Ok(value) => {
                     match value {
-                        &Literal::NullLiteral => {
+                        Literal::NullLiteral => {
                             self.report_error(InterpretError::UnassignmedVariable(format!(
                                 "Variable {} is not assigned",
                                 token_name
@@ -342,7 +1380,7 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
                             return None
                         }
                         _ =>  {
-                            return Some(value.clone())
+                            return Some(value)
                         },
                     }
                     // return Some(value.clone())
@@ -358,18 +1396,23 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
 
     fn visit_logical_expression(&mut self, expr: &Expr) -> Option<Literal> {
         if let Expr::Logical(left_expr, operator, right_expr) = expr {
+            // `??` exists to handle null, so reading an unassigned variable is not an error there
+            if operator.token_type() == &TokenType::QUESTIONQUESTION {
+                let left_value = self.evaluate_nullable(left_expr)?;
+                if !matches!(left_value, Literal::NullLiteral) {
+                    return Some(left_value);
+                }
+                return self.evaluate_nullable(right_expr);
+            }
+
             let left_value = self.evaluate(left_expr)?;
 
             match operator.token_type() {
-                TokenType::OR => {
-                    if self.is_truthy(&left_value) {
-                        return Some(left_value);
-                    }
-                },
-                TokenType::AND => {
-                    if !self.is_truthy(&left_value) {
-                        return Some(left_value);
-                    }
+                TokenType::OR if self.is_truthy(&left_value) => {
+                    return Some(left_value);
+                }
+                TokenType::AND if !self.is_truthy(&left_value) => {
+                    return Some(left_value);
                 }
                 _ => {}
             }
@@ -381,8 +1424,17 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
     }
 
     fn visit_call_expression(&mut self, expr: &Expr) -> Option<Literal> {
-        if let Expr::Call(callee, _paren, args) = expr {
-            let caller = self.evaluate(callee)?;
+        if let Expr::Call(callee, _paren, args, named, optional) = expr {
+            let caller = if *optional {
+                self.evaluate_nullable(callee)?
+            } else {
+                self.evaluate(callee)?
+            };
+
+            // `fn?.()` skips the call, including its arguments, when the callee is null
+            if *optional && matches!(caller, Literal::NullLiteral) {
+                return Some(Literal::NullLiteral);
+            }
 
             let mut func_args: Vec<Literal> = Vec::new();
             for argument in args {
@@ -393,20 +1445,32 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
                 }
             }
 
+            let mut named_args: Vec<(Token, Literal)> = Vec::new();
+            for (name, argument) in named {
+                if let Some(arg) = self.evaluate(argument) {
+                    named_args.push((name.clone(), arg));
+                } else {
+                    return None;
+                }
+            }
+
             if let Some(callable) = caller.as_callable() {
-                // Verify argument count matches the arity of the function
-                if func_args.len() != callable.arity() {
+                // Verify argument count fits the arity of the function
+                let supplied = func_args.len() + named_args.len();
+                let min_arity = callable.min_arity();
+                let max_arity = callable.max_arity();
+                if supplied < min_arity || max_arity.is_some_and(|max| supplied > max) {
                     self.report_error(InterpretError::ArgumentMismatch(
                         format!(
                             "Expected {} arguments but got {}.",
-                            callable.arity(),
-                            func_args.len()
+                            describe_arity(min_arity, max_arity),
+                            supplied
                         ),
                     ));
                     return None;
                 }
 
-                return callable.call(self, func_args)
+                return callable.call(self, func_args, named_args)
             } else {
                 self.report_error(InterpretError::TypeMismatch(
                     "Can only call functions and classes.".to_string(),
@@ -417,23 +1481,270 @@ impl ExprVisitor<Option<Literal>> for Interpreter {
 
         None
     }
+
+    fn visit_conditional_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Conditional(condition, then_expr, else_expr) = expr {
+            let condition_value = self.evaluate(condition)?;
+
+            // Only the chosen branch is evaluated
+            if self.is_truthy(&condition_value) {
+                return self.evaluate(then_expr);
+            } else {
+                return self.evaluate(else_expr);
+            }
+        }
+
+        None
+    }
+
+    fn visit_list_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::List(elements) = expr {
+            let mut items: Vec<Literal> = Vec::new();
+            for element in elements {
+                items.push(self.evaluate(element)?);
+            }
+
+            return Some(Literal::new_list(items));
+        }
+
+        None
+    }
+
+    fn visit_map_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Map(entries) = expr {
+            let mut map: Vec<(Literal, Literal)> = Vec::new();
+            for (key, value) in entries {
+                let key = self.evaluate(key)?;
+                if !self.check_hashable(&key, "map key") {
+                    return None;
+                }
+                let value = self.evaluate(value)?;
+
+                // A repeated key keeps its first position but takes the later value
+                self.map_insert(&mut map, key, value);
+            }
+
+            return Some(Literal::new_map(map));
+        }
+
+        None
+    }
+
+    fn visit_set_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Set(elements) = expr {
+            let mut items: Vec<Literal> = Vec::new();
+            for (element, spread) in elements {
+                let value = self.evaluate(element)?;
+                let values = if *spread { self.iterate(&value)? } else { vec![value] };
+
+                for value in values {
+                    if !self.check_hashable(&value, "set element") {
+                        return None;
+                    }
+                    self.set_insert(&mut items, value);
+                }
+            }
+
+            return Some(Literal::new_set(items));
+        }
+
+        None
+    }
+
+    fn visit_tuple_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Tuple(elements) = expr {
+            let mut items: Vec<Literal> = Vec::new();
+            for element in elements {
+                items.push(self.evaluate(element)?);
+            }
+
+            return Some(Literal::new_tuple(items));
+        }
+
+        None
+    }
+
+    fn visit_index_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Index(object, _bracket, index) = expr {
+            let object = self.evaluate(object)?;
+            let index = self.evaluate(index)?;
+            return self.read_element(&object, &index);
+        }
+
+        None
+    }
+
+    fn visit_set_index_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::SetIndex(object, _bracket, index, value) = expr {
+            let object = self.evaluate(object)?;
+            let index = self.evaluate(index)?;
+            let value = self.evaluate(value)?;
+            return self.write_element(&object, index, value);
+        }
+
+        None
+    }
+
+    fn visit_compound_set_index_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::CompoundSetIndex(object, _bracket, index, operator, value_expr) = expr {
+            // The object and index are evaluated once, so `xs[next(ids)] += 1` reads and writes the same item
+            let object = self.evaluate(object)?;
+            let index = self.evaluate(index)?;
+            let current = self.read_element(&object, &index)?;
+            let value = self.evaluate(value_expr)?;
+
+            let binary_operator = self.compound_operator(operator)?;
+            let result = self.apply_binary_operator(&binary_operator, current, value)?;
+            return self.write_element(&object, index, result);
+        }
+
+        None
+    }
+
+    fn visit_update_index_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::UpdateIndex(object, _bracket, index, operator, prefix) = expr {
+            let object = self.evaluate(object)?;
+            let index = self.evaluate(index)?;
+            let current = self.read_element(&object, &index)?;
+
+            let (old, new) = self.step_number(operator, current)?;
+            self.write_element(&object, index, Literal::NumberLiteral(new))?;
+
+            // Prefix form yields the new value, postfix form the old one
+            return Some(Literal::NumberLiteral(if *prefix { new } else { old }));
+        }
+
+        None
+    }
+
+    fn visit_destructure_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Destructure(pattern, _equals, value_expr) = expr {
+            let value = self.evaluate(value_expr)?;
+
+            // Every target is checked before any is assigned, so `[a, b] = [b, a]` swaps
+            let mut bindings: Vec<(Token, Literal)> = Vec::new();
+            if !self.destructure(pattern, &value, &mut bindings) {
+                return None;
+            }
+
+            for (name, bound) in bindings {
+                let assigned = self.environment.borrow_mut().assign(&name, bound);
+                if let Err(error) = assigned {
+                    self.report_error(error);
+                    return None;
+                }
+            }
+
+            return Some(value);
+        }
+
+        None
+    }
+
+    fn visit_get_expression(&mut self, expr: &Expr) -> Option<Literal> {
+        if let Expr::Get(object, name, optional) = expr {
+            let value = if *optional {
+                self.evaluate_nullable(object)?
+            } else {
+                self.evaluate(object)?
+            };
+
+            // `obj?.name` is null when obj is null
+            if *optional && matches!(value, Literal::NullLiteral) {
+                return Some(Literal::NullLiteral);
+            }
+
+            if let Literal::Error(error) = &value {
+                match name.lexeme() {
+                    "kind" => return Some(Literal::StringLiteral(error.kind().to_string())),
+                    "message" => return Some(Literal::StringLiteral(error.message())),
+                    "line" => return Some(Literal::NumberLiteral(error.line() as f64)),
+                    _ => {}
+                }
+            }
+
+            if let Literal::Map(entries) = &value {
+                let key = Literal::StringLiteral(name.lexeme().to_string());
+                if let Some(member) = self.map_get(&entries.borrow(), &key) {
+                    return Some(member);
+                }
+
+                self.report_error(InterpretError::UndefinedProperty(format!(
+                    "Map has no key '{}'",
+                    name.lexeme()
+                )));
+                return None;
+            }
+
+            if let Literal::Enum(enum_type) = &value {
+                if let Some(member) = EnumType::member(enum_type, name.lexeme()) {
+                    return Some(member);
+                }
+
+                self.report_error(InterpretError::UndefinedProperty(format!(
+                    "Enum '{}' has no variant '{}'",
+                    enum_type.name(),
+                    name.lexeme()
+                )));
+                return None;
+            }
+
+            if let Literal::Variant(variant) = &value {
+                if let Some(member) = variant.field(name.lexeme()) {
+                    return Some(member);
+                }
+
+                self.report_error(InterpretError::UndefinedProperty(format!(
+                    "{} has no field '{}'",
+                    variant,
+                    name.lexeme()
+                )));
+                return None;
+            }
+
+            if let Some(method) = self.builtin_method(&value, name.lexeme()) {
+                return Some(method);
+            }
+
+            if let Literal::Namespace(namespace) = &value {
+                if let Some(member) = namespace.get(name.lexeme()) {
+                    return Some(member);
+                }
+
+                self.report_error(InterpretError::UndefinedProperty(format!(
+                    "Module '{}' does not export '{}'",
+                    namespace.name(),
+                    name.lexeme()
+                )));
+                return None;
+            }
+
+            self.report_error(InterpretError::UndefinedProperty(format!(
+                "Undefined property '{}' on {}",
+                name.lexeme(),
+                value
+            )));
+        }
+
+        None
+    }
 }
 
 impl StmtVisitor<()> for Interpreter {
-    fn visit_block_stmt(&mut self, stmt: &Stmt) -> () {
+    fn visit_block_stmt(&mut self, stmt: &Stmt) {
         if let Stmt::Block(stmt_list) = stmt {
-            let new_env = Environment::new_with_env(Box::new(self.environment.clone()));
+            let new_env = Environment::new_with_env(Rc::clone(&self.environment));
             self.execute_block(stmt_list.clone(), new_env);
         }
     }
 
-    fn visit_expression_stmt(&mut self, stmt: &Stmt) -> () {
+    fn visit_expression_stmt(&mut self, stmt: &Stmt) {
         if let Stmt::Expression(expr) = stmt {
             self.evaluate(expr);
         }
     }
 
-    fn visit_print_stmt(&mut self, stmt: &Stmt) -> () {
+    fn visit_print_stmt(&mut self, stmt: &Stmt) {
         if let Stmt::Print(expr) = stmt {
             if let Some(value) = self.evaluate(expr) {
                 println!("{}", value);
@@ -441,7 +1752,7 @@ impl StmtVisitor<()> for Interpreter {
         }
     }
 
-    fn visit_var_stmt(&mut self, stmt: &Stmt) -> () {
+    fn visit_var_stmt(&mut self, stmt: &Stmt) {
         if let Stmt::Var(token, initializer) = stmt {
             let value = if let Some(expr) = initializer {
                 self.evaluate(expr)
@@ -450,14 +1761,49 @@ impl StmtVisitor<()> for Interpreter {
             };
 
             if let Some(val) = value {
-                self.environment.define(token.lexeme(), val);
+                self.environment.borrow_mut().define(token.lexeme(), val);
+            }
+        }
+    }
+
+    fn visit_const_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Const(token, initializer) = stmt {
+            if let Some(value) = self.evaluate(initializer) {
+                self.environment.borrow_mut().define_constant(token.lexeme(), value);
+            }
+        }
+    }
+
+    fn visit_destructure_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Destructure(pattern, initializer, constant) = stmt {
+            let value = match self.evaluate(initializer) {
+                Some(value) => value,
+                None => return,
+            };
+
+            let mut bindings: Vec<(Token, Literal)> = Vec::new();
+            if !self.destructure(pattern, &value, &mut bindings) {
+                return;
+            }
+
+            for (name, bound) in bindings {
+                if *constant {
+                    self.environment.borrow_mut().define_constant(name.lexeme(), bound);
+                } else {
+                    self.environment.borrow_mut().define(name.lexeme(), bound);
+                }
             }
         }
     }
 
-    fn visit_if_stmt(&mut self, stmt: &Stmt) -> () {
+    fn visit_if_stmt(&mut self, stmt: &Stmt) {
         if let Stmt::If(condition, then_branch, else_branch) = stmt {
-            if let Some(Literal::BooleanLiteral(true)) = self.evaluate(condition) {
+            // Same rule as loops and `cond ? a : b`: only `false` and null are false
+            let Some(condition_value) = self.evaluate(condition) else {
+                return;
+            };
+
+            if self.is_truthy(&condition_value) {
                 self.execute(then_branch);
             } else if let Some(else_branch) = else_branch {
                 self.execute(else_branch);
@@ -465,30 +1811,235 @@ impl StmtVisitor<()> for Interpreter {
         }
     }
 
-    fn visit_while_stmt(&mut self, stmt: &Stmt) -> () {
-        if let Stmt::While(condition, body) = stmt {
+    fn visit_while_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::While(condition, body, increment) = stmt {
                 while let Some(cond_value) = self.evaluate(condition) {
                     if !self.is_truthy(&cond_value) {
                         break;
                     }
 
                     self.execute(body);
+                    if self.loop_should_exit() {
+                        break;
+                    }
+
+                    if let Some(increment) = increment {
+                        if self.evaluate(increment).is_none() {
+                            break;
+                        }
+                    }
+                }
+        }
+    }
+
+    fn visit_do_while_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::DoWhile(body, condition) = stmt {
+            loop {
+                self.execute(body);
+                if self.loop_should_exit() {
+                    break;
+                }
+
+                match self.evaluate(condition) {
+                    Some(cond_value) if self.is_truthy(&cond_value) => {}
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    fn visit_loop_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Loop(body) = stmt {
+            loop {
+                self.execute(body);
+                if self.loop_should_exit() {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::ForIn(name, iterable, body) = stmt {
+            let Some(collection) = self.evaluate(iterable) else {
+                return;
+            };
+
+            // A generator is resumed once per iteration, so it may be endless
+            if let Literal::Generator(generator) = collection {
+                while let Some(Some(item)) = generator.resume(self) {
+                    if self.run_for_in_body(name, item, body) {
+                        break;
+                    }
+                }
+                return;
+            }
+
+            // Lists are walked as they were when the loop started
+            let Some(items) = self.iterate(&collection) else {
+                return;
+            };
+
+            for item in items {
+                if self.run_for_in_body(name, item, body) {
+                    break;
                 }
+            }
         }
     }
 
-    fn visit_function_stmt(&mut self, stmt: &Stmt) -> () {
+    fn visit_yield_stmt(&mut self, _stmt: &Stmt) {
+        // Generators run their `yield`s themselves; one reaching here is inside a `try` or outside any function
+        self.report_error(InterpretError::UnexpectedError(
+            "Cannot yield here; yield only works in a function body outside 'try'".to_string(),
+        ));
+    }
+
+    fn visit_break_stmt(&mut self, _stmt: &Stmt) {
+        self.unwinding = Some(Unwind::Break);
+    }
+
+    fn visit_continue_stmt(&mut self, _stmt: &Stmt) {
+        self.unwinding = Some(Unwind::Continue);
+    }
+
+    fn visit_function_stmt(&mut self, stmt: &Stmt) {
         if let Stmt::Function(name, params, body) = stmt {
-            let function = NepLatFunc::new(name.clone(), params.clone(), body.clone());
+            // The function closes over the scope it is declared in
+            let function = NepLatFunc::new(name.clone(), params.clone(), body.clone(), Rc::clone(&self.environment));
             let function_literal = Literal::Callable(Rc::new(function));
 
-            self.environment.define(name.lexeme(), function_literal);
+            self.environment.borrow_mut().define(name.lexeme(), function_literal);
+        }
+    }
+
+    fn visit_enum_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Enum(name, variants) = stmt {
+            let variants = variants
+                .iter()
+                .map(|(variant, fields)| {
+                    let fields = fields.iter().map(|field| field.lexeme().to_string()).collect();
+                    (variant.lexeme().to_string(), fields)
+                })
+                .collect();
+            let enum_type = EnumType::new(name.lexeme(), variants);
+
+            self.environment.borrow_mut().define(name.lexeme(), Literal::Enum(Rc::new(enum_type)));
+        }
+    }
+
+    fn visit_return_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Return(_keyword, value) = stmt {
+            let value = match value {
+                Some(expr) => match self.evaluate(expr) {
+                    Some(value) => value,
+                    None => return,
+                },
+                None => Literal::NullLiteral,
+            };
+
+            self.unwinding = Some(Unwind::Return(value));
+        }
+    }
+
+    fn visit_match_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Match(_keyword, subject, arms) = stmt {
+            let value = match self.evaluate(subject) {
+                Some(value) => value,
+                None => return,
+            };
+
+            if let Some((index, arm_env)) = self.select_match_arm(&value, arms) {
+                let previous = std::mem::replace(&mut self.environment, arm_env);
+                self.execute(&arms[index].body);
+                self.environment = previous;
+            }
+        }
+    }
+
+    fn visit_throw_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Throw(keyword, value) = stmt {
+            let value = match self.evaluate(value) {
+                Some(value) => value,
+                None => return,
+            };
+
+            match value {
+                // Rethrowing a caught error keeps its original line and trace
+                Literal::Error(error) => self.throw(error),
+                other => {
+                    self.line = *keyword.line();
+                    self.report_error(InterpretError::Thrown(other));
+                }
+            }
+        }
+    }
+
+    fn visit_try_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Try(body, catch_name, catch_body, finally_body) = stmt {
+            let try_env = Environment::new_with_env(Rc::clone(&self.environment));
+            self.execute_block(body.clone(), try_env);
+
+            if let Some(catch_body) = catch_body {
+                if matches!(self.unwinding, Some(Unwind::Throw(_))) {
+                    if let Some(Unwind::Throw(error)) = self.unwinding.take() {
+                        let mut catch_env = Environment::new_with_env(Rc::clone(&self.environment));
+                        if let Some(name) = catch_name {
+                            catch_env.define(name.lexeme(), Literal::Error(error));
+                        }
+                        self.execute_block(catch_body.clone(), catch_env);
+                    }
+                }
+            }
+
+            if let Some(finally_body) = finally_body {
+                // finally runs even while unwinding; a new throw or return inside it wins
+                let pending = self.unwinding.take();
+                let finally_env = Environment::new_with_env(Rc::clone(&self.environment));
+                self.execute_block(finally_body.clone(), finally_env);
+
+                if self.unwinding.is_none() {
+                    self.unwinding = pending;
+                }
+            }
+        }
+    }
+
+    fn visit_import_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Import(keyword, path, alias) = stmt {
+            self.line = *keyword.line();
+
+            let resolved = match self.resolve_module_path(path.lexeme()) {
+                Some(resolved) => resolved,
+                None => {
+                    self.report_error(InterpretError::ImportError(format!(
+                        "Cannot find module '{}'",
+                        path.lexeme()
+                    )));
+                    return;
+                }
+            };
+
+            // With a file root, modules must come from inside it as well, or `import` would read around it
+            if self.file_access.root().is_some_and(|root| !resolved.starts_with(root)) {
+                self.report_error(InterpretError::ImportError(format!(
+                    "Cannot import '{}'; it is outside the allowed directory",
+                    path.lexeme()
+                )));
+                return;
+            }
+
+            if let Some(namespace) = self.load_module(&resolved, path.lexeme()) {
+                self.environment
+                    .borrow_mut()
+                    .define(alias.lexeme(), Literal::Namespace(namespace));
+            }
         }
     }
 
-    fn visit_return_stmt(&mut self, stmt: &Stmt) -> () {
-        if let Stmt::Return(_keyword, _value) = stmt {
-            todo!()
+    fn visit_export_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Export(declaration) = stmt {
+            self.execute(declaration);
         }
     }
 }
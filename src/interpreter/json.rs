@@ -0,0 +1,408 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Write, rc::Rc};
+
+use crate::lexer::Literal;
+
+use super::{
+    environment::Environment,
+    interpret_error::InterpretError,
+    module::Namespace,
+    native::{Arity, NativeFunction},
+};
+
+// Deeper nesting than this is rejected instead of overflowing the stack
+const MAX_DEPTH: usize = 256;
+
+// The `json` module: `json.parse(text)` and `json.stringify(value, indent)`
+pub fn namespace() -> Namespace {
+    let parse = NativeFunction::new("parse", 1, |_, arguments| parse(arguments.string(0)?));
+    let stringify = NativeFunction::new("stringify", Arity::range(1, 2), |_, arguments| {
+        let indent = match arguments.get(1) {
+            Some(_) => arguments.integer(1)?.clamp(0, 10) as usize,
+            None => 0,
+        };
+        Ok(Literal::StringLiteral(stringify(&arguments.value(0)?, indent)?))
+    });
+
+    let mut environment = Environment::new();
+    environment.define("parse", Literal::Callable(Rc::new(parse)));
+    environment.define("stringify", Literal::Callable(Rc::new(stringify)));
+    let exports = vec!["parse".to_string(), "stringify".to_string()];
+    Namespace::new("json", Rc::new(RefCell::new(environment)), exports)
+}
+
+// Objects become maps with string keys, arrays become lists
+pub fn parse(text: &str) -> Result<Literal, InterpretError> {
+    let mut parser = JsonParser {
+        chars: text.chars().collect(),
+        current: 0,
+        line: 1,
+        column: 1,
+    };
+
+    parser.skip_whitespace();
+    let value = parser.value(0)?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected text after the value"));
+    }
+    Ok(value)
+}
+
+// An `indent` above zero puts each item on its own line, indented by that many spaces per level
+pub fn stringify(value: &Literal, indent: usize) -> Result<String, InterpretError> {
+    let mut output = String::new();
+    write_value(&mut output, value, indent, 0)?;
+    Ok(output)
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    current: usize,
+    line: usize,
+    column: usize,
+}
+
+impl JsonParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.current).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.current += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, message: &str) -> InterpretError {
+        InterpretError::JsonError(format!(
+            "Invalid JSON at line {}, column {}: {}",
+            self.line, self.column, message
+        ))
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), InterpretError> {
+        if self.peek() == Some(expected) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.advance();
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Literal, InterpretError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+
+        match self.peek() {
+            Some('{') => self.object(depth),
+            Some('[') => self.array(depth),
+            Some('"') => Ok(Literal::StringLiteral(self.string()?)),
+            Some('-' | '0'..='9') => self.number(),
+            Some('t') => self.word("true", Literal::BooleanLiteral(true)),
+            Some('f') => self.word("false", Literal::BooleanLiteral(false)),
+            Some('n') => self.word("null", Literal::NullLiteral),
+            Some(_) => Err(self.error("expected a value")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn word(&mut self, word: &str, value: Literal) -> Result<Literal, InterpretError> {
+        for expected in word.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("expected '{}'", word)));
+            }
+            self.advance();
+        }
+        Ok(value)
+    }
+
+    fn object(&mut self, depth: usize) -> Result<Literal, InterpretError> {
+        self.advance();
+        let mut entries: Vec<(Literal, Literal)> = Vec::new();
+        // A repeated key keeps its first position and its last value
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.advance();
+            return Ok(Literal::Map(Rc::new(RefCell::new(entries))));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("expected a string key"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            let value = self.value(depth + 1)?;
+
+            match positions.get(&key) {
+                Some(&position) => entries[position].1 = value,
+                None => {
+                    positions.insert(key.clone(), entries.len());
+                    entries.push((Literal::StringLiteral(key), value));
+                }
+            }
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.advance(),
+                Some('}') => {
+                    self.advance();
+                    return Ok(Literal::Map(Rc::new(RefCell::new(entries))));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            };
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<Literal, InterpretError> {
+        self.advance();
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.advance();
+            return Ok(Literal::List(Rc::new(RefCell::new(items))));
+        }
+
+        loop {
+            self.skip_whitespace();
+            items.push(self.value(depth + 1)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.advance(),
+                Some(']') => {
+                    self.advance();
+                    return Ok(Literal::List(Rc::new(RefCell::new(items))));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            };
+        }
+    }
+
+    fn string(&mut self) -> Result<String, InterpretError> {
+        self.advance();
+        let mut text = String::new();
+
+        loop {
+            match self.advance() {
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    let escaped = match self.advance() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.unicode_escape()?,
+                        _ => return Err(self.error("invalid escape in string")),
+                    };
+                    text.push(escaped);
+                }
+                Some(c) if c < ' ' => return Err(self.error("control character in string")),
+                Some(c) => text.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    // `\uXXXX`, where characters outside the Basic Multilingual Plane come as a surrogate pair
+    fn unicode_escape(&mut self) -> Result<char, InterpretError> {
+        let first = self.hex_digits()?;
+        if !(0xD800..0xDC00).contains(&first) {
+            return char::from_u32(first).ok_or_else(|| self.error("invalid unicode escape"));
+        }
+
+        if self.advance() != Some('\\') || self.advance() != Some('u') {
+            return Err(self.error("unpaired surrogate in unicode escape"));
+        }
+        let second = self.hex_digits()?;
+        if !(0xDC00..0xE000).contains(&second) {
+            return Err(self.error("unpaired surrogate in unicode escape"));
+        }
+        let code = 0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00);
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, InterpretError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .advance()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("expected four hex digits"))?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    fn number(&mut self) -> Result<Literal, InterpretError> {
+        let start = self.current;
+        if self.peek() == Some('-') {
+            self.advance();
+        }
+
+        match self.peek() {
+            Some('0') => {
+                self.advance();
+            }
+            Some('1'..='9') => self.digits(),
+            _ => return Err(self.error("expected a digit")),
+        }
+        if self.peek() == Some('.') {
+            self.advance();
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.error("expected a digit after '.'"));
+            }
+            self.digits();
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.advance();
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.advance();
+            }
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.error("expected a digit in the exponent"));
+            }
+            self.digits();
+        }
+
+        let text: String = self.chars[start..self.current].iter().collect();
+        match text.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(Literal::NumberLiteral(number)),
+            _ => Err(self.error("number is too large")),
+        }
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.advance();
+        }
+    }
+}
+
+fn write_value(output: &mut String, value: &Literal, indent: usize, depth: usize) -> Result<(), InterpretError> {
+    if depth > MAX_DEPTH {
+        return Err(InterpretError::JsonError(
+            "Cannot write JSON nested this deeply; does a list contain itself?".to_string(),
+        ));
+    }
+
+    match value {
+        Literal::NullLiteral => output.push_str("null"),
+        Literal::BooleanLiteral(boolean) => output.push_str(if *boolean { "true" } else { "false" }),
+        Literal::NumberLiteral(number) if number.is_finite() => {
+            let _ = write!(output, "{}", number);
+        }
+        Literal::StringLiteral(text) => write_string(output, text),
+        Literal::List(items) => write_array(output, &items.borrow(), indent, depth)?,
+        Literal::Tuple(items) => write_array(output, items, indent, depth)?,
+        Literal::Set(items) => write_array(output, items, indent, depth)?,
+        Literal::Map(entries) => {
+            let entries = entries.borrow();
+            let mut keys = Vec::with_capacity(entries.len());
+            for (key, _) in entries.iter() {
+                match key {
+                    Literal::StringLiteral(key) => keys.push(key),
+                    other => {
+                        return Err(InterpretError::TypeMismatch(format!(
+                            "JSON object keys must be strings, found a {}",
+                            other.type_name()
+                        )))
+                    }
+                }
+            }
+
+            let items = keys.into_iter().zip(entries.iter().map(|(_, value)| value));
+            write_container(output, ('{', '}'), items, indent, depth, |output, (key, value)| {
+                write_string(output, key);
+                output.push(':');
+                if indent > 0 {
+                    output.push(' ');
+                }
+                write_value(output, value, indent, depth + 1)
+            })?;
+        }
+        other => {
+            return Err(InterpretError::TypeMismatch(format!(
+                "Cannot write a {} as JSON",
+                other.type_name()
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn write_array(output: &mut String, items: &[Literal], indent: usize, depth: usize) -> Result<(), InterpretError> {
+    write_container(output, ('[', ']'), items.iter(), indent, depth, |output, item| {
+        write_value(output, item, indent, depth + 1)
+    })
+}
+
+fn write_container<T>(
+    output: &mut String,
+    (open, close): (char, char),
+    items: impl ExactSizeIterator<Item = T>,
+    indent: usize,
+    depth: usize,
+    mut write_item: impl FnMut(&mut String, T) -> Result<(), InterpretError>,
+) -> Result<(), InterpretError> {
+    output.push(open);
+    let empty = items.len() == 0;
+
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            output.push(',');
+        }
+        if indent > 0 {
+            output.push('\n');
+            output.push_str(&" ".repeat(indent * (depth + 1)));
+        }
+        write_item(output, item)?;
+    }
+
+    if indent > 0 && !empty {
+        output.push('\n');
+        output.push_str(&" ".repeat(indent * depth));
+    }
+    output.push(close);
+    Ok(())
+}
+
+fn write_string(output: &mut String, text: &str) {
+    output.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
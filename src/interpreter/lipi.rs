@@ -0,0 +1,196 @@
+// Romanized Nepali to Devanagari and back. The scheme is close to ITRANS:
+//
+// - vowels: a aa/A i ii/I/ee u uu/U/oo Ri e ai o au; after a consonant they become signs, and `a` is the
+//   sound every consonant already has, so `ka` is क and `kaa` is का
+// - consonants: k kh g gh ~N ch chh j jh ~n T Th D Dh N t th d dh n p ph/f b bh m y r l w/v sh Sh s h ksh;
+//   capitals mark the retroflex letters, so `T` is ट and `t` is त
+// - case only matters for T D N Sh A I U Ri M H, so `Namaste` is णमस्ते and only `namaste` is नमस्ते; any other
+//   capital is read as its small letter, so `Kamal` is कमल
+// - consonants written together join (`namaste` is नमस्ते); a consonant at the end of a word keeps its
+//   vowel (`raam` is राम), and `.h` ends it with a halanta instead (`bhagawaan.h` is भगवान्)
+// - M ं, H ः, .N ँ, | ।, || ॥, digits become ०-९, and `_` separates letters that would otherwise merge
+//   (`ka_i` is कइ, while `kai` is कै)
+
+const CONSONANTS: [(&str, &str); 37] = [
+    ("k", "क"), ("kh", "ख"), ("g", "ग"), ("gh", "घ"), ("~N", "ङ"),
+    ("ch", "च"), ("chh", "छ"), ("j", "ज"), ("jh", "झ"), ("~n", "ञ"),
+    ("T", "ट"), ("Th", "ठ"), ("D", "ड"), ("Dh", "ढ"), ("N", "ण"),
+    ("t", "त"), ("th", "थ"), ("d", "द"), ("dh", "ध"), ("n", "न"),
+    ("p", "प"), ("ph", "फ"), ("f", "फ"), ("b", "ब"), ("bh", "भ"), ("m", "म"),
+    ("y", "य"), ("r", "र"), ("l", "ल"), ("w", "व"), ("v", "व"),
+    ("sh", "श"), ("Sh", "ष"), ("s", "स"), ("h", "ह"), ("ksh", "क्ष"),
+    // Not a letter on its own, but common enough in Nepali to deserve a shortcut
+    ("jny", "ज्ञ"),
+];
+
+// (romanized, independent letter, sign after a consonant)
+const VOWELS: [(&str, &str, &str); 16] = [
+    ("a", "अ", ""), ("aa", "आ", "ा"), ("A", "आ", "ा"),
+    ("i", "इ", "ि"), ("ii", "ई", "ी"), ("I", "ई", "ी"), ("ee", "ई", "ी"),
+    ("u", "उ", "ु"), ("uu", "ऊ", "ू"), ("U", "ऊ", "ू"), ("oo", "ऊ", "ू"),
+    ("Ri", "ऋ", "ृ"), ("e", "ए", "े"), ("ai", "ऐ", "ै"), ("o", "ओ", "ो"), ("au", "औ", "ौ"),
+];
+
+const MARKS: [(&str, &str); 5] = [("M", "ं"), ("H", "ः"), (".N", "ँ"), ("|", "।"), ("||", "॥")];
+
+const HALANTA: &str = "्";
+const ROMAN_HALANTA: &str = ".h";
+const SEPARATOR: char = '_';
+
+#[derive(Clone, Copy)]
+enum Piece {
+    Consonant(&'static str),
+    Vowel(&'static str, &'static str),
+    Mark(&'static str),
+    Halanta,
+}
+
+// Every romanized spelling with what it stands for
+fn pieces() -> impl Iterator<Item = (&'static str, Piece)> {
+    CONSONANTS
+        .iter()
+        .map(|&(roman, letter)| (roman, Piece::Consonant(letter)))
+        .chain(VOWELS.iter().map(|&(roman, letter, sign)| (roman, Piece::Vowel(letter, sign))))
+        .chain(MARKS.iter().map(|&(roman, mark)| (roman, Piece::Mark(mark))))
+        .chain(std::iter::once((ROMAN_HALANTA, Piece::Halanta)))
+}
+
+// The longest spelling `text` starts with
+fn longest_piece(text: &str) -> Option<(&'static str, Piece)> {
+    pieces()
+        .filter(|(roman, _)| text.starts_with(roman))
+        .max_by_key(|(roman, _)| roman.len())
+}
+
+// The longest spelling once a leading capital with no meaning of its own is lowercased, e.g. the `K` of `Kathmandu`
+fn longest_piece_lowercased(text: &str) -> Option<(&'static str, Piece)> {
+    let first = text.chars().next().filter(char::is_ascii_uppercase)?;
+    longest_piece(&format!("{}{}", first.to_ascii_lowercase(), &text[1..]))
+}
+
+pub fn to_devanagari(text: &str) -> String {
+    let mut output = String::new();
+    // Whether the last letter was a consonant still waiting for its vowel
+    let mut pending = false;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == SEPARATOR {
+            rest = &rest[1..];
+            continue;
+        }
+
+        let Some((roman, piece)) = longest_piece(rest).or_else(|| longest_piece_lowercased(rest)) else {
+            pending = false;
+            output.push(c.to_digit(10).and_then(|digit| char::from_u32('०' as u32 + digit)).unwrap_or(c));
+            rest = &rest[c.len_utf8()..];
+            continue;
+        };
+        rest = &rest[roman.len()..];
+
+        match piece {
+            Piece::Consonant(letter) => {
+                if pending {
+                    output.push_str(HALANTA);
+                }
+                output.push_str(letter);
+                pending = true;
+            }
+            Piece::Vowel(letter, sign) => {
+                output.push_str(if pending { sign } else { letter });
+                pending = false;
+            }
+            Piece::Mark(mark) => {
+                output.push_str(mark);
+                pending = false;
+            }
+            Piece::Halanta => {
+                if pending {
+                    output.push_str(HALANTA);
+                }
+                pending = false;
+            }
+        }
+    }
+    output
+}
+
+pub fn to_roman(text: &str) -> String {
+    let mut writer = RomanWriter::default();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let letter = c.to_string();
+
+        if let Some(roman) = roman_consonant(&letter) {
+            writer.push(roman);
+            match chars.peek().map(|next| next.to_string()) {
+                Some(next) if next == HALANTA => {
+                    chars.next();
+                    // Before another consonant the halanta is implied by writing them together
+                    let joins = chars.peek().is_some_and(|after| roman_consonant(&after.to_string()).is_some());
+                    if !joins {
+                        writer.push(ROMAN_HALANTA);
+                    }
+                }
+                Some(next) if roman_sign(&next).is_some() => {
+                    chars.next();
+                    writer.push(roman_sign(&next).unwrap_or("a"));
+                }
+                _ => writer.push("a"),
+            }
+        } else if let Some(roman) = roman_vowel(&letter).or_else(|| roman_mark(&letter)) {
+            writer.push(roman);
+        } else if let Some(digit) = ('०'..='९').position(|digit| digit == c) {
+            writer.push_other(char::from(b'0' + digit as u8));
+        } else {
+            writer.push_other(c);
+        }
+    }
+    writer.output
+}
+
+// Builds romanized text, adding `_` wherever two spellings would read back as one
+#[derive(Default)]
+struct RomanWriter {
+    output: String,
+    last: Option<&'static str>,
+}
+
+impl RomanWriter {
+    fn push(&mut self, roman: &'static str) {
+        if let Some(last) = self.last {
+            let joined = format!("{}{}", last, roman);
+            if longest_piece(&joined).is_some_and(|(piece, _)| piece.len() > last.len()) {
+                self.output.push(SEPARATOR);
+            }
+        }
+        self.output.push_str(roman);
+        self.last = Some(roman);
+    }
+
+    fn push_other(&mut self, c: char) {
+        self.output.push(c);
+        self.last = None;
+    }
+}
+
+// The first spelling in the tables is the one written back
+fn roman_consonant(letter: &str) -> Option<&'static str> {
+    CONSONANTS.iter().find(|(_, consonant)| *consonant == letter).map(|(roman, _)| *roman)
+}
+
+fn roman_vowel(letter: &str) -> Option<&'static str> {
+    VOWELS.iter().find(|(_, vowel, _)| *vowel == letter).map(|(roman, _, _)| *roman)
+}
+
+fn roman_sign(sign: &str) -> Option<&'static str> {
+    VOWELS
+        .iter()
+        .find(|(_, _, vowel_sign)| !vowel_sign.is_empty() && *vowel_sign == sign)
+        .map(|(roman, _, _)| *roman)
+}
+
+fn roman_mark(mark: &str) -> Option<&'static str> {
+    MARKS.iter().find(|(_, devanagari)| *devanagari == mark).map(|(roman, _)| *roman)
+}
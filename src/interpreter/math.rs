@@ -0,0 +1,134 @@
+use std::{
+    cell::{Cell, RefCell},
+    f64::consts,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::lexer::Literal;
+
+use super::{
+    environment::Environment,
+    interpret_error::InterpretError,
+    module::Namespace,
+    native::{Arguments, Arity, NativeFunction},
+};
+
+type Unary = fn(f64) -> f64;
+
+// Functions of one number, e.g. `math.sqrt(2)`
+const UNARY: [(&str, Unary); 10] = [
+    ("sqrt", f64::sqrt),
+    ("abs", f64::abs),
+    ("floor", f64::floor),
+    ("ceil", f64::ceil),
+    ("round", f64::round),
+    ("sin", f64::sin),
+    ("cos", f64::cos),
+    ("tan", f64::tan),
+    ("asin", f64::asin),
+    ("acos", f64::acos),
+];
+
+// The `math`/`ganit` module every script can use
+pub fn namespace() -> Namespace {
+    let mut environment = Environment::new();
+    let mut exports = Vec::new();
+    let mut define = |name: &str, value: Literal| {
+        environment.define(name, value);
+        exports.push(name.to_string());
+    };
+
+    define("PI", Literal::NumberLiteral(consts::PI));
+    define("E", Literal::NumberLiteral(consts::E));
+
+    for (name, function) in UNARY {
+        define(name, native(name, 1, move |arguments| {
+            let number = arguments.number(0)?;
+            defined(name, number, function(number))
+        }));
+    }
+    define("atan", native("atan", 1, |arguments| Ok(arguments.number(0)?.atan())));
+    define("atan2", native("atan2", 2, |arguments| Ok(arguments.number(0)?.atan2(arguments.number(1)?))));
+    define("pow", native("pow", 2, |arguments| {
+        let (base, exponent) = (arguments.number(0)?, arguments.number(1)?);
+        defined("pow", base, base.powf(exponent))
+    }));
+    define("min", native("min", Arity::at_least(1), |arguments| fold(arguments, f64::min)));
+    define("max", native("max", Arity::at_least(1), |arguments| fold(arguments, f64::max)));
+
+    // One generator shared by `seed`, `random` and `random_int`; it starts from the clock
+    let state = Rc::new(Cell::new(clock_seed()));
+    let random = Rc::clone(&state);
+    let random_int = Rc::clone(&state);
+    let seed = NativeFunction::new("seed", 1, move |_, arguments| {
+        state.set(arguments.integer(0)? as u64);
+        Ok(Literal::NullLiteral)
+    });
+    define("seed", Literal::Callable(Rc::new(seed)));
+    define("random", native("random", 0, move |_| Ok(next_fraction(&random))));
+    define("random_int", native("random_int", 2, move |arguments| {
+        let (low, high) = (arguments.integer(0)?, arguments.integer(1)?);
+        if low > high {
+            return Err(InterpretError::ArgumentMismatch(format!(
+                "random_int() needs low <= high, got {} and {}",
+                low, high
+            )));
+        }
+        // In i128 so the widest range, i64::MIN to i64::MAX, cannot overflow
+        let span = high as i128 - low as i128 + 1;
+        let offset = next_u64(&random_int) as i128 % span;
+        Ok((low as i128 + offset) as f64)
+    }));
+
+    Namespace::new("math", Rc::new(RefCell::new(environment)), exports)
+}
+
+fn native(
+    name: &str,
+    arity: impl Into<Arity>,
+    function: impl Fn(&Arguments) -> Result<f64, InterpretError> + 'static,
+) -> Literal {
+    let native = NativeFunction::new(name, arity, move |_, arguments| {
+        function(&arguments).map(Literal::NumberLiteral)
+    });
+    Literal::Callable(Rc::new(native))
+}
+
+// A NaN result means the input was outside the function's domain, e.g. `sqrt(-1)`
+fn defined(name: &str, input: f64, result: f64) -> Result<f64, InterpretError> {
+    if result.is_nan() && !input.is_nan() {
+        return Err(InterpretError::ArgumentMismatch(format!("{}() is not defined for {}", name, input)));
+    }
+    Ok(result)
+}
+
+fn fold(arguments: &Arguments, pick: fn(f64, f64) -> f64) -> Result<f64, InterpretError> {
+    let mut result = arguments.number(0)?;
+    for index in 1..arguments.len() {
+        result = pick(result, arguments.number(index)?);
+    }
+    Ok(result)
+}
+
+fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+// SplitMix64: small, fast and fine for lessons and games, not for secrets
+fn next_u64(state: &Cell<u64>) -> u64 {
+    let seed = state.get().wrapping_add(0x9E37_79B9_7F4A_7C15);
+    state.set(seed);
+    let mut z = seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+// A number from 0 up to but not including 1
+fn next_fraction(state: &Cell<u64>) -> f64 {
+    (next_u64(state) >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -2,5 +2,17 @@ pub mod interpreter;
 pub mod interpret_error;
 pub mod environment;
 pub mod callable;
+pub mod module;
+pub mod enumeration;
+pub mod generator;
+pub mod native;
+pub mod prelude;
+pub mod math;
+pub mod files;
+pub mod json;
+pub mod date;
+pub mod numbers;
+pub mod lipi;
+pub mod strings;
 
 pub use interpreter::Interpreter;
\ No newline at end of file
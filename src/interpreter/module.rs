@@ -0,0 +1,55 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use crate::lexer::Literal;
+
+use super::environment::Environment;
+
+// The value an import binds: a module's environment seen through the names it exports
+pub struct Namespace {
+    name: String,
+    environment: Rc<RefCell<Environment>>,
+    exports: Vec<String>,
+}
+
+impl Namespace {
+    pub fn new(name: &str, environment: Rc<RefCell<Environment>>, exports: Vec<String>) -> Self {
+        Namespace {
+            name: name.to_string(),
+            environment,
+            exports,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn exports(&self) -> &[String] {
+        &self.exports
+    }
+
+    // Reads the current value of an export, so later changes inside the module are visible
+    pub fn get(&self, name: &str) -> Option<Literal> {
+        if !self.exports.iter().any(|export| export == name) {
+            return None;
+        }
+
+        self.environment.borrow().get(name).ok()
+    }
+}
+
+// The environment holds functions that close over it, so it is left out to avoid endless output
+impl fmt::Debug for Namespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Namespace")
+            .field("name", &self.name)
+            .field("exports", &self.exports)
+            .finish()
+    }
+}
+
+impl fmt::Display for Namespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<module {}>", self.name)
+    }
+}
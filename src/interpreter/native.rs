@@ -0,0 +1,234 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use crate::lexer::{Literal, Token};
+
+use super::{
+    callable::{describe_arity, Callable},
+    generator::Generator,
+    interpret_error::InterpretError,
+    Interpreter,
+};
+
+// The body of a native function; an `Err` is raised in the script like any runtime error
+pub type NativeFn = dyn Fn(&mut Interpreter, Arguments) -> Result<Literal, InterpretError>;
+
+// How many arguments a native function accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arity {
+    min: usize,
+    // `None` means any number of extra arguments is accepted
+    max: Option<usize>,
+}
+
+impl Arity {
+    pub const fn exact(count: usize) -> Self {
+        Arity { min: count, max: Some(count) }
+    }
+
+    pub const fn range(min: usize, max: usize) -> Self {
+        Arity { min, max: Some(max) }
+    }
+
+    pub const fn at_least(min: usize) -> Self {
+        Arity { min, max: None }
+    }
+
+    // The arity of a method once the value it was read from is passed in front of its arguments
+    pub const fn with_receiver(self) -> Self {
+        Arity {
+            min: self.min + 1,
+            max: match self.max {
+                Some(max) => Some(max + 1),
+                None => None,
+            },
+        }
+    }
+}
+
+impl From<usize> for Arity {
+    fn from(count: usize) -> Self {
+        Arity::exact(count)
+    }
+}
+
+impl fmt::Display for Arity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", describe_arity(self.min, self.max))
+    }
+}
+
+// The arguments a native function was called with, read by position with the type it expects
+#[derive(Debug)]
+pub struct Arguments {
+    function: String,
+    values: Vec<Literal>,
+}
+
+impl Arguments {
+    pub fn new(function: &str, values: Vec<Literal>) -> Self {
+        Arguments {
+            function: function.to_string(),
+            values,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // An argument that may have been left out
+    pub fn get(&self, index: usize) -> Option<&Literal> {
+        self.values.get(index)
+    }
+
+    pub fn value(&self, index: usize) -> Result<Literal, InterpretError> {
+        self.values.get(index).cloned().ok_or_else(|| self.missing(index))
+    }
+
+    pub fn number(&self, index: usize) -> Result<f64, InterpretError> {
+        self.expect(index, "number", |value| match value {
+            Literal::NumberLiteral(number) => Some(*number),
+            _ => None,
+        })
+    }
+
+    // A number without a fractional part, as used for indexes and counts
+    pub fn integer(&self, index: usize) -> Result<i64, InterpretError> {
+        self.expect(index, "whole number", |value| match value {
+            Literal::NumberLiteral(number) if number.fract() == 0.0 => Some(*number as i64),
+            _ => None,
+        })
+    }
+
+    pub fn string(&self, index: usize) -> Result<&str, InterpretError> {
+        self.expect(index, "string", |value| match value {
+            Literal::StringLiteral(string) => Some(string.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn boolean(&self, index: usize) -> Result<bool, InterpretError> {
+        self.expect(index, "boolean", |value| match value {
+            Literal::BooleanLiteral(boolean) => Some(*boolean),
+            _ => None,
+        })
+    }
+
+    pub fn list(&self, index: usize) -> Result<Rc<RefCell<Vec<Literal>>>, InterpretError> {
+        self.expect(index, "list", |value| match value {
+            Literal::List(list) => Some(Rc::clone(list)),
+            _ => None,
+        })
+    }
+
+    pub fn callable(&self, index: usize) -> Result<Rc<dyn Callable>, InterpretError> {
+        self.expect(index, "function", |value| match value {
+            Literal::Callable(callable) => Some(Rc::clone(callable)),
+            _ => None,
+        })
+    }
+
+    pub fn generator(&self, index: usize) -> Result<Rc<Generator>, InterpretError> {
+        self.expect(index, "generator", |value| match value {
+            Literal::Generator(generator) => Some(Rc::clone(generator)),
+            _ => None,
+        })
+    }
+
+    pub fn into_vec(self) -> Vec<Literal> {
+        self.values
+    }
+
+    fn expect<'a, T>(
+        &'a self,
+        index: usize,
+        expected: &str,
+        convert: impl FnOnce(&'a Literal) -> Option<T>,
+    ) -> Result<T, InterpretError> {
+        let value = self.values.get(index).ok_or_else(|| self.missing(index))?;
+        convert(value).ok_or_else(|| {
+            InterpretError::TypeMismatch(format!(
+                "{}() expects a {} as argument {}, found a {}",
+                self.function,
+                expected,
+                index + 1,
+                value.type_name()
+            ))
+        })
+    }
+
+    fn missing(&self, index: usize) -> InterpretError {
+        InterpretError::ArgumentMismatch(format!("{}() is missing argument {}", self.function, index + 1))
+    }
+}
+
+// A function written in Rust; the interpreter's built-ins and anything registered by an embedding program
+pub struct NativeFunction {
+    name: String,
+    arity: Arity,
+    function: Box<NativeFn>,
+}
+
+impl NativeFunction {
+    pub fn new(
+        name: &str,
+        arity: impl Into<Arity>,
+        function: impl Fn(&mut Interpreter, Arguments) -> Result<Literal, InterpretError> + 'static,
+    ) -> Self {
+        NativeFunction {
+            name: name.to_string(),
+            arity: arity.into(),
+            function: Box::new(function),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+impl fmt::Display for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+impl Callable for NativeFunction {
+    fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Literal>, named: Vec<(Token, Literal)>) -> Option<Literal> {
+        if let Some((name, _)) = named.first() {
+            interpreter.report_error(InterpretError::ArgumentMismatch(format!(
+                "{} has no parameter named '{}'.",
+                self,
+                name.lexeme()
+            )));
+            return None;
+        }
+
+        // An error raised while another one is already unwinding (e.g. from a script callback) is dropped
+        match (self.function)(interpreter, Arguments::new(&self.name, arguments)) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                interpreter.report_error(error);
+                None
+            }
+        }
+    }
+
+    fn min_arity(&self) -> usize {
+        self.arity.min
+    }
+
+    fn max_arity(&self) -> Option<usize> {
+        self.arity.max
+    }
+}
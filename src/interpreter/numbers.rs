@@ -0,0 +1,185 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::lexer::Literal;
+
+use super::{
+    environment::Environment,
+    interpret_error::InterpretError,
+    module::Namespace,
+    native::{Arguments, Arity, NativeFunction},
+    Interpreter,
+};
+
+// Nepali names of 0 to 99; the tens are not regular enough to build from parts
+const ROMAN: [&str; 100] = [
+    "sunya", "ek", "dui", "tin", "char", "panch", "chha", "sat", "aath", "nau",
+    "das", "eghar", "bahra", "tehra", "chaudha", "pandhra", "sohra", "satra", "athara", "unnais",
+    "bis", "ekkais", "bais", "teis", "chaubis", "pachchis", "chhabbis", "sattais", "aththais", "unantis",
+    "tis", "ektis", "battis", "tettis", "chauntis", "paitis", "chhattis", "saitis", "athtis", "unanchalis",
+    "chalis", "ekchalis", "bayalis", "trichalis", "chawalis", "paitalis", "chhayalis", "satchalis", "athchalis", "unanchas",
+    "pachas", "ekaunna", "baunna", "tripanna", "chaunna", "pachpanna", "chhapanna", "santaunna", "anthaunna", "unansathi",
+    "sathi", "eksatthi", "bayasatthi", "trisatthi", "chausatthi", "paisatthi", "chhayasatthi", "satsatthi", "athsatthi", "unansattari",
+    "sattari", "ekhattar", "bahattar", "trihattar", "chauhattar", "pachahattar", "chhayahattar", "satahattar", "athahattar", "unasi",
+    "asi", "ekasi", "bayasi", "triyasi", "chaurasi", "pachasi", "chhayasi", "satasi", "athasi", "unanabbe",
+    "nabbe", "ekanabbe", "bayanabbe", "triyanabbe", "chauranabbe", "panchanabbe", "chhayanabbe", "santanabbe", "anthanabbe", "unansaya",
+];
+
+const DEVANAGARI: [&str; 100] = [
+    "शून्य", "एक", "दुई", "तीन", "चार", "पाँच", "छ", "सात", "आठ", "नौ",
+    "दश", "एघार", "बाह्र", "तेह्र", "चौध", "पन्ध्र", "सोह्र", "सत्र", "अठार", "उन्नाइस",
+    "बीस", "एक्काइस", "बाइस", "तेइस", "चौबीस", "पच्चीस", "छब्बीस", "सत्ताइस", "अठ्ठाइस", "उनन्तीस",
+    "तीस", "एकतीस", "बत्तीस", "तेत्तीस", "चौँतीस", "पैँतीस", "छत्तीस", "सैँतीस", "अठतीस", "उनन्चालीस",
+    "चालीस", "एकचालीस", "बयालीस", "त्रिचालीस", "चवालीस", "पैँतालीस", "छयालीस", "सत्चालीस", "अठचालीस", "उनन्चास",
+    "पचास", "एकाउन्न", "बाउन्न", "त्रिपन्न", "चउन्न", "पचपन्न", "छपन्न", "सन्ताउन्न", "अन्ठाउन्न", "उनन्साठी",
+    "साठी", "एकसट्ठी", "बयसट्ठी", "त्रिसट्ठी", "चौसट्ठी", "पैँसट्ठी", "छयसट्ठी", "सतसट्ठी", "अठसट्ठी", "उनन्सत्तरी",
+    "सत्तरी", "एकहत्तर", "बहत्तर", "त्रिहत्तर", "चौहत्तर", "पचहत्तर", "छयहत्तर", "सतहत्तर", "अठहत्तर", "उनासी",
+    "असी", "एकासी", "बयासी", "त्रियासी", "चौरासी", "पचासी", "छयासी", "सतासी", "अठासी", "उनान्नब्बे",
+    "नब्बे", "एकानब्बे", "बयानब्बे", "त्रियानब्बे", "चौरानब्बे", "पन्चानब्बे", "छयानब्बे", "सन्तानब्बे", "अन्ठानब्बे", "उनान्सय",
+];
+
+// (value, romanized, Devanagari), largest first; each place holds 0 to 99 except `saya`
+const PLACES: [(i64, &str, &str); 6] = [
+    (100_000_000_000, "kharab", "खर्ब"),
+    (1_000_000_000, "arab", "अर्ब"),
+    (10_000_000, "crore", "करोड"),
+    (100_000, "lakh", "लाख"),
+    (1_000, "hajar", "हजार"),
+    (100, "saya", "सय"),
+];
+
+// Beyond 99 kharab there is no next place to name
+const LARGEST: i64 = 10_000_000_000_000 - 1;
+
+type NumberFunction = fn(&Arguments) -> Result<Literal, InterpretError>;
+
+// The `numbers`/`ank` module
+pub fn namespace() -> Namespace {
+    let functions: [(&str, Arity, NumberFunction); 4] = [
+        ("group", Arity::range(1, 2), |arguments| {
+            let decimals = match arguments.get(1) {
+                Some(_) => Some(arguments.integer(1)?.clamp(0, 10) as usize),
+                None => None,
+            };
+            Ok(Literal::StringLiteral(group(arguments.number(0)?, decimals)))
+        }),
+        ("words", Arity::range(1, 2), |arguments| {
+            let number = arguments.integer(0)?;
+            Ok(Literal::StringLiteral(words(number, script(arguments)?)?))
+        }),
+        ("rupees", Arity::range(1, 2), |arguments| {
+            Ok(Literal::StringLiteral(rupees(arguments.number(0)?, script(arguments)?)?))
+        }),
+        ("devanagari", Arity::exact(1), |arguments| {
+            Ok(Literal::StringLiteral(devanagari_digits(&arguments.value(0)?.to_string())))
+        }),
+    ];
+
+    let mut environment = Environment::new();
+    let mut exports = Vec::new();
+    for (name, arity, function) in functions {
+        let native = NativeFunction::new(name, arity, move |_: &mut Interpreter, arguments| function(&arguments));
+        environment.define(name, Literal::Callable(Rc::new(native)));
+        exports.push(name.to_string());
+    }
+    Namespace::new("numbers", Rc::new(RefCell::new(environment)), exports)
+}
+
+// The optional second argument: `true` for Devanagari words, romanized otherwise
+fn script(arguments: &Arguments) -> Result<bool, InterpretError> {
+    match arguments.get(1) {
+        Some(_) => arguments.boolean(1),
+        None => Ok(false),
+    }
+}
+
+// South Asian grouping: the last three digits, then pairs, as in `1,00,00,000`
+pub fn group(number: f64, decimals: Option<usize>) -> String {
+    let text = match decimals {
+        Some(decimals) => format!("{:.*}", decimals, number.abs()),
+        None => number.abs().to_string(),
+    };
+    let (whole, fraction) = match text.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (text.as_str(), None),
+    };
+
+    let digits: Vec<char> = whole.chars().collect();
+    let mut grouped = String::new();
+    for (i, digit) in digits.iter().enumerate() {
+        let remaining = digits.len() - i;
+        if i > 0 && remaining >= 3 && (remaining - 3).is_multiple_of(2) {
+            grouped.push(',');
+        }
+        grouped.push(*digit);
+    }
+
+    let sign = if number < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') { "-" } else { "" };
+    match fraction {
+        Some(fraction) => format!("{}{}.{}", sign, grouped, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+// A whole number in Nepali words, e.g. 1,25,000 is "ek lakh pachchis hajar"
+pub fn words(number: i64, devanagari: bool) -> Result<String, InterpretError> {
+    // unsigned_abs, as `i64::MIN` has no positive counterpart
+    if number.unsigned_abs() > LARGEST as u64 {
+        return Err(InterpretError::ArgumentMismatch(format!(
+            "words() can only spell numbers up to {}",
+            group(LARGEST as f64, None)
+        )));
+    }
+
+    let names = if devanagari { &DEVANAGARI } else { &ROMAN };
+    if number == 0 {
+        return Ok(names[0].to_string());
+    }
+
+    let mut parts = Vec::new();
+    if number < 0 {
+        parts.push(if devanagari { "ऋण" } else { "rin" });
+    }
+
+    let mut remaining = number.abs();
+    for (value, roman, nepali) in PLACES {
+        let count = remaining / value;
+        remaining %= value;
+        if count > 0 {
+            parts.push(names[count as usize]);
+            parts.push(if devanagari { nepali } else { roman });
+        }
+    }
+    if remaining > 0 {
+        parts.push(names[remaining as usize]);
+    }
+    Ok(parts.join(" "))
+}
+
+// An amount of money in words, with anything after the decimal point as paisa
+pub fn rupees(amount: f64, devanagari: bool) -> Result<String, InterpretError> {
+    let total_paisa = (amount.abs() * 100.0).round() as i64;
+    let (rupees, paisa) = (total_paisa / 100, total_paisa % 100);
+    let (rupee_word, paisa_word) = if devanagari { ("रुपैयाँ", "पैसा") } else { ("rupaiya", "paisa") };
+
+    let mut parts = Vec::new();
+    if amount < 0.0 && total_paisa > 0 {
+        parts.push(if devanagari { "ऋण" } else { "rin" }.to_string());
+    }
+    if rupees > 0 || paisa == 0 {
+        parts.push(format!("{} {}", words(rupees, devanagari)?, rupee_word));
+    }
+    if paisa > 0 {
+        parts.push(format!("{} {}", words(paisa, devanagari)?, paisa_word));
+    }
+    Ok(parts.join(" "))
+}
+
+// Replaces the digits 0-9 with ०-९ and leaves everything else alone
+pub fn devanagari_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => char::from_u32('०' as u32 + digit).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
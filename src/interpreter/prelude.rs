@@ -0,0 +1,157 @@
+use std::{
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::lexer::Literal;
+
+use super::{
+    date, files,
+    interpret_error::InterpretError,
+    json, lipi, math, numbers,
+    native::{Arguments, Arity},
+    Interpreter,
+};
+
+type Builtin = fn(&mut Interpreter, Arguments) -> Result<Literal, InterpretError>;
+
+// The functions every script starts with, under their English and Nepali names
+pub fn register(interpreter: &mut Interpreter) {
+    let builtins: [(&str, &str, Arity, Builtin); 9] = [
+        ("clock", "samaya", Arity::exact(0), clock),
+        ("type", "prakar", Arity::exact(1), type_of),
+        ("num", "sankhya", Arity::exact(1), num),
+        ("str", "shabda", Arity::exact(1), str),
+        ("bool", "satyata", Arity::exact(1), bool),
+        ("len", "lambai", Arity::exact(1), len),
+        ("next", "arko", Arity::range(1, 2), next),
+        ("input", "lekha", Arity::range(0, 1), input),
+        ("transliterate", "lipi", Arity::range(1, 2), transliterate),
+    ];
+
+    for (english, nepali, arity, function) in builtins {
+        interpreter.register_native(english, arity, function);
+        interpreter.register_native(nepali, arity, function);
+    }
+
+    let math = Literal::Namespace(Rc::new(math::namespace()));
+    interpreter.register_global("math", math.clone());
+    interpreter.register_global("ganit", math);
+
+    let files = Literal::Namespace(Rc::new(files::namespace()));
+    interpreter.register_global("files", files.clone());
+    interpreter.register_global("kagaj", files);
+
+    interpreter.register_global("json", Literal::Namespace(Rc::new(json::namespace())));
+
+    let date = Literal::Namespace(Rc::new(date::namespace()));
+    interpreter.register_global("date", date.clone());
+    interpreter.register_global("miti", date);
+
+    let numbers = Literal::Namespace(Rc::new(numbers::namespace()));
+    interpreter.register_global("numbers", numbers.clone());
+    interpreter.register_global("ank", numbers);
+}
+
+// Seconds since the Unix epoch, for timing code
+fn clock(_: &mut Interpreter, _: Arguments) -> Result<Literal, InterpretError> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| InterpretError::UnexpectedError("The system clock is set before 1970".to_string()))?;
+    Ok(Literal::NumberLiteral(elapsed.as_secs_f64()))
+}
+
+fn type_of(_: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    Ok(Literal::StringLiteral(arguments.value(0)?.type_name().to_string()))
+}
+
+fn num(_: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    match arguments.value(0)? {
+        Literal::NumberLiteral(number) => Ok(Literal::NumberLiteral(number)),
+        Literal::BooleanLiteral(boolean) => Ok(Literal::NumberLiteral(if boolean { 1.0 } else { 0.0 })),
+        // Only finite numbers, so "inf" and "NaN" stay strings
+        Literal::StringLiteral(text) => match text.trim().parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(Literal::NumberLiteral(number)),
+            _ => Err(InterpretError::TypeMismatch(format!("num() cannot turn \"{}\" into a number", text))),
+        },
+        other => Err(InterpretError::TypeMismatch(format!(
+            "num() cannot turn a {} into a number",
+            other.type_name()
+        ))),
+    }
+}
+
+fn str(_: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    Ok(Literal::StringLiteral(arguments.value(0)?.to_string()))
+}
+
+// Strings must spell a boolean; anything else follows the truthiness `if` uses
+fn bool(interpreter: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    match arguments.value(0)? {
+        Literal::StringLiteral(text) => match text.trim() {
+            "true" | "satya" => Ok(Literal::BooleanLiteral(true)),
+            "false" | "galat" => Ok(Literal::BooleanLiteral(false)),
+            _ => Err(InterpretError::TypeMismatch(format!("bool() cannot turn \"{}\" into a boolean", text))),
+        },
+        other => Ok(Literal::BooleanLiteral(interpreter.is_truthy(&other))),
+    }
+}
+
+fn len(_: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    let length = match arguments.value(0)? {
+        Literal::StringLiteral(text) => text.chars().count(),
+        Literal::List(items) => items.borrow().len(),
+        Literal::Tuple(items) => items.len(),
+        Literal::Map(entries) => entries.borrow().len(),
+        Literal::Set(items) => items.len(),
+        other => {
+            return Err(InterpretError::TypeMismatch(format!(
+                "len() expects a string, list, tuple, map or set, found a {}",
+                other.type_name()
+            )))
+        }
+    };
+    Ok(Literal::NumberLiteral(length as f64))
+}
+
+// `input(prompt)` reads a line typed by the user; null once the input has ended
+fn input(interpreter: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    let prompt = arguments.get(0).map(|prompt| prompt.to_string());
+
+    match interpreter.read_line(prompt.as_deref()) {
+        Ok(Some(line)) => Ok(Literal::StringLiteral(line)),
+        Ok(None) => Ok(Literal::NullLiteral),
+        Err(error) => Err(InterpretError::UnexpectedError(format!("input() could not read a line: {}", error))),
+    }
+}
+
+// `lipi("namaste")` is "नमस्ते"; `lipi("नमस्ते", "roman")` goes the other way. Capital T, D, N, Sh, A, I, U,
+// Ri, M and H are different letters, so `lipi("Namaste")` is "णमस्ते"; other capitals read as small letters
+fn transliterate(_: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    let text = arguments.string(0)?;
+    let target = match arguments.get(1) {
+        Some(_) => arguments.string(1)?,
+        None => "devanagari",
+    };
+
+    match target {
+        "devanagari" => Ok(Literal::StringLiteral(lipi::to_devanagari(text))),
+        "roman" => Ok(Literal::StringLiteral(lipi::to_roman(text))),
+        other => Err(InterpretError::ArgumentMismatch(format!(
+            "transliterate() can write \"devanagari\" or \"roman\", not \"{}\"",
+            other
+        ))),
+    }
+}
+
+// `next(generator)` resumes a generator; once it is finished the optional second argument (or null) comes back
+fn next(interpreter: &mut Interpreter, arguments: Arguments) -> Result<Literal, InterpretError> {
+    let generator = arguments.generator(0)?;
+
+    match generator.resume(interpreter) {
+        Some(Some(value)) => Ok(value),
+        Some(None) => Ok(arguments.get(1).cloned().unwrap_or(Literal::NullLiteral)),
+        // The body has already reported its error
+        None => Err(InterpretError::UnexpectedError(format!("{} failed", generator))),
+    }
+}
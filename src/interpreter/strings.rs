@@ -0,0 +1,149 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::lexer::Literal;
+
+use super::{
+    interpret_error::InterpretError,
+    native::{Arguments, Arity, NativeFunction},
+};
+
+// The longest string `repeat` will build, in bytes
+const LONGEST_REPEAT: usize = 100_000_000;
+
+type StringMethod = fn(&str, &Arguments) -> Result<Literal, InterpretError>;
+
+// (English name, Nepali name, arguments, body); indexes count characters, so Devanagari works
+const METHODS: [(&str, &str, Arity, StringMethod); 11] = [
+    ("slice", "tukra", Arity::range(1, 2), slice),
+    ("find", "khoja", Arity::exact(1), find),
+    ("replace", "badla", Arity::exact(2), replace),
+    ("split", "chhuttyau", Arity::range(0, 1), split),
+    ("join", "jodau", Arity::exact(1), join),
+    ("trim", "chhatau", Arity::exact(0), trim),
+    ("upper", "thulo", Arity::exact(0), upper),
+    ("lower", "sano", Arity::exact(0), lower),
+    ("starts_with", "suru_huncha", Arity::exact(1), starts_with),
+    ("ends_with", "anta_huncha", Arity::exact(1), ends_with),
+    ("repeat", "dohorau", Arity::exact(1), repeat),
+];
+
+// The method `name` of strings, taking the string it is bound to as its first argument
+pub fn method(name: &str) -> Option<NativeFunction> {
+    let (_, _, arity, function) = METHODS
+        .iter()
+        .find(|(english, nepali, _, _)| *english == name || *nepali == name)?;
+
+    let function = *function;
+    let method_name = name.to_string();
+    Some(NativeFunction::new(name, arity.with_receiver(), move |_, arguments| {
+        // The rest are numbered from 1 in errors, the way the script passed them
+        let text = arguments.string(0)?.to_string();
+        let rest = Arguments::new(&method_name, arguments.into_vec().split_off(1));
+        function(&text, &rest)
+    }))
+}
+
+fn string(text: String) -> Result<Literal, InterpretError> {
+    Ok(Literal::StringLiteral(text))
+}
+
+// A negative index counts from the end; indexes past either end stop there
+fn clamp_index(index: i64, length: usize) -> usize {
+    let length = length as i64;
+    let index = if index < 0 { index + length } else { index };
+    index.clamp(0, length) as usize
+}
+
+// `text.slice(start, end)`: the characters from `start` up to but not including `end`
+fn slice(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    let characters: Vec<char> = text.chars().collect();
+    let start = clamp_index(arguments.integer(0)?, characters.len());
+    let end = match arguments.get(1) {
+        Some(_) => clamp_index(arguments.integer(1)?, characters.len()),
+        None => characters.len(),
+    };
+
+    if start >= end {
+        return string(String::new());
+    }
+    string(characters[start..end].iter().collect())
+}
+
+// The character index of the first match, or -1
+fn find(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    let position = match text.find(arguments.string(0)?) {
+        Some(byte_index) => text[..byte_index].chars().count() as f64,
+        None => -1.0,
+    };
+    Ok(Literal::NumberLiteral(position))
+}
+
+fn replace(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    string(text.replace(arguments.string(0)?, arguments.string(1)?))
+}
+
+// Without a separator the text is split on whitespace; an empty separator splits it into characters
+fn split(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    let parts: Vec<Literal> = match arguments.get(0) {
+        None => text.split_whitespace().map(|part| Literal::StringLiteral(part.to_string())).collect(),
+        Some(_) => match arguments.string(0)? {
+            "" => text.chars().map(|c| Literal::StringLiteral(c.to_string())).collect(),
+            separator => text.split(separator).map(|part| Literal::StringLiteral(part.to_string())).collect(),
+        },
+    };
+    Ok(Literal::List(Rc::new(RefCell::new(parts))))
+}
+
+// `", ".join(items)` puts the text between the items, which are shown the way `print` shows them
+fn join(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    let items = match arguments.value(0)? {
+        Literal::List(items) => items.borrow().clone(),
+        Literal::Tuple(items) => items.to_vec(),
+        other => {
+            return Err(InterpretError::TypeMismatch(format!(
+                "join() expects a list or tuple as argument 1, found a {}",
+                other.type_name()
+            )))
+        }
+    };
+
+    let parts: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    string(parts.join(text))
+}
+
+fn trim(text: &str, _: &Arguments) -> Result<Literal, InterpretError> {
+    string(text.trim().to_string())
+}
+
+fn upper(text: &str, _: &Arguments) -> Result<Literal, InterpretError> {
+    string(text.to_uppercase())
+}
+
+fn lower(text: &str, _: &Arguments) -> Result<Literal, InterpretError> {
+    string(text.to_lowercase())
+}
+
+fn starts_with(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    Ok(Literal::BooleanLiteral(text.starts_with(arguments.string(0)?)))
+}
+
+fn ends_with(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    Ok(Literal::BooleanLiteral(text.ends_with(arguments.string(0)?)))
+}
+
+fn repeat(text: &str, arguments: &Arguments) -> Result<Literal, InterpretError> {
+    let count = arguments.integer(0)?;
+    if count < 0 {
+        return Err(InterpretError::ArgumentMismatch(format!(
+            "repeat() cannot repeat a string {} times",
+            count
+        )));
+    }
+    match text.len().checked_mul(count as usize) {
+        Some(length) if length <= LONGEST_REPEAT => string(text.repeat(count as usize)),
+        _ => Err(InterpretError::ArgumentMismatch(format!(
+            "repeat() cannot make a string longer than {} bytes",
+            LONGEST_REPEAT
+        ))),
+    }
+}
@@ -45,12 +45,64 @@ impl<'a> Lexer<'a> {
             b')' => Some(self.create_token(TokenType::RIGHTPAREN)),
             b'{' => Some(self.create_token(TokenType::LEFTBRACE)),
             b'}' => Some(self.create_token(TokenType::RIGHTBRACE)),
+            b'[' => Some(self.create_token(TokenType::LEFTBRACKET)),
+            b']' => Some(self.create_token(TokenType::RIGHTBRACKET)),
             b',' => Some(self.create_token(TokenType::COMMA)),
-            b'.' => Some(self.create_token(TokenType::DOT)),
-            b'-' => Some(self.create_token(TokenType::MINUS)),
-            b'+' => Some(self.create_token(TokenType::PLUS)),
+            b'.' => {
+                if self.match_char('.') {
+                    if self.match_char('.') {
+                        return Some(self.create_token(TokenType::DOTDOTDOT));
+                    }
+                    if self.match_char('=') {
+                        return Some(self.create_token(TokenType::DOTDOTEQUAL));
+                    }
+                    return Some(self.create_token(TokenType::DOTDOT));
+                }
+                Some(self.create_token(TokenType::DOT))
+            }
+            b'-' => {
+                if self.match_char('=') {
+                    return Some(self.create_token(TokenType::MINUSEQUAL));
+                }
+                if self.match_char('-') {
+                    return Some(self.create_token(TokenType::MINUSMINUS));
+                }
+                Some(self.create_token(TokenType::MINUS))
+            }
+            b'+' => {
+                if self.match_char('=') {
+                    return Some(self.create_token(TokenType::PLUSEQUAL));
+                }
+                if self.match_char('+') {
+                    return Some(self.create_token(TokenType::PLUSPLUS));
+                }
+                Some(self.create_token(TokenType::PLUS))
+            }
             b';' => Some(self.create_token(TokenType::SEMICOLON)),
-            b'*' => Some(self.create_token(TokenType::STAR)),
+            b'*' => {
+                if self.match_char('=') {
+                    return Some(self.create_token(TokenType::STAREQUAL));
+                }
+                Some(self.create_token(TokenType::STAR))
+            }
+            b'%' => {
+                if self.match_char('=') {
+                    return Some(self.create_token(TokenType::PERCENTEQUAL));
+                }
+                Some(self.create_token(TokenType::PERCENT))
+            }
+            b':' => Some(self.create_token(TokenType::COLON)),
+            b'|' => Some(self.create_token(TokenType::PIPE)),
+            b'&' => Some(self.create_token(TokenType::AMPERSAND)),
+            b'?' => {
+                if self.match_char('?') {
+                    return Some(self.create_token(TokenType::QUESTIONQUESTION));
+                }
+                if self.match_char('.') {
+                    return Some(self.create_token(TokenType::QUESTIONDOT));
+                }
+                Some(self.create_token(TokenType::QUESTION))
+            }
             b'!' => {
                 if self.match_char('=') {
                     return Some(self.create_token(TokenType::BANGEQUAL));
@@ -61,6 +113,9 @@ impl<'a> Lexer<'a> {
                 if self.match_char('=') {
                     return Some(self.create_token(TokenType::EQUALEQUAL));
                 }
+                if self.match_char('>') {
+                    return Some(self.create_token(TokenType::FATARROW));
+                }
                 Some(self.create_token(TokenType::EQUAL))
             }
             b'<' => {
@@ -98,6 +153,8 @@ impl<'a> Lexer<'a> {
                     }
 
                     None
+                } else if self.match_char('=') {
+                    Some(self.create_token(TokenType::SLASHEQUAL))
                 } else {
                     Some(self.create_token(TokenType::SLASH))
                 }
@@ -143,6 +200,22 @@ impl<'a> Lexer<'a> {
             "class" | "samuha" => TokenType::CLASS,
             "this" | "yei" => TokenType::THIS,
             "super" | "affnai" => TokenType::SUPER,
+            "match" | "milau" => TokenType::MATCH,
+            "throw" | "phyaka" => TokenType::THROW,
+            "try" | "prayas" => TokenType::TRY,
+            "catch" | "samatnus" => TokenType::CATCH,
+            "finally" | "antama" => TokenType::FINALLY,
+            "import" | "ayat" => TokenType::IMPORT,
+            "as" | "bhanera" => TokenType::AS,
+            "export" | "niryat" => TokenType::EXPORT,
+            "const" | "sthir" => TokenType::CONST,
+            "do" | "gara" => TokenType::DO,
+            "loop" | "dohoryau" => TokenType::LOOP,
+            "break" | "roka" => TokenType::BREAK,
+            "continue" | "jari" => TokenType::CONTINUE,
+            "in" | "ma" => TokenType::IN,
+            "enum" | "ganana" => TokenType::ENUM,
+            "yield" | "pathau" => TokenType::YIELD,
             "ghatau" => TokenType::MINUS,
             "joda" => TokenType::PLUS,
             "ulto" => TokenType::BANG,
@@ -153,6 +226,15 @@ impl<'a> Lexer<'a> {
             "thulo_wa_barabar" => TokenType::GREATEREQUAL,
             "bhanda_sano" => TokenType::LESS,
             "sano_wa_barabar" => TokenType::LESSEQUAL,
+            "joda_bhaneko" => TokenType::PLUSEQUAL,
+            "ghatau_bhaneko" => TokenType::MINUSEQUAL,
+            "guna_bhaneko" => TokenType::STAREQUAL,
+            "bhag_bhaneko" => TokenType::SLASHEQUAL,
+            "shesh_bhaneko" => TokenType::PERCENTEQUAL,
+            "ek_joda" => TokenType::PLUSPLUS,
+            "ek_ghatau" => TokenType::MINUSMINUS,
+            "khali_bhaye" => TokenType::QUESTIONQUESTION,
+            "chha_bhane" => TokenType::QUESTIONDOT,
             _ => TokenType::IDENTIFIER,
         };
 
@@ -166,10 +248,11 @@ impl<'a> Lexer<'a> {
             value.push(self.advance() as char);
         }
 
-        if self.peek() == '.' {
+        // `1..5` is a range, so the dot only belongs to the number when a digit follows it
+        if self.peek() == '.' && self.peek_next() != '.' {
             value.push(self.advance() as char);
 
-            if !self.peek_next().is_ascii_digit() {
+            if !self.peek().is_ascii_digit() {
                 self.errors.push(LexError::InvalidNumber(self.line));
                 return Token::new(TokenType::NUMBER, &value, None, self.line);
             }
@@ -1,6 +1,12 @@
-use std::{fmt, rc::Rc};
+use std::{cell::RefCell, fmt, rc::Rc};
 
-use crate::interpreter::callable::Callable;
+use crate::interpreter::{
+    callable::Callable,
+    enumeration::{EnumType, Variant},
+    generator::Generator,
+    interpret_error::ErrorValue,
+    module::Namespace,
+};
 
 use super::TokenType;
 
@@ -58,9 +64,58 @@ pub enum Literal {
     BooleanLiteral(bool),
     NullLiteral,
     Callable(Rc<dyn Callable>),
+    List(Rc<RefCell<Vec<Literal>>>),
+    // Fixed once built, so it can be shared freely and used as a map key
+    Tuple(Rc<Vec<Literal>>),
+    // (key, value) entries in insertion order; keys are hashable values
+    Map(Rc<RefCell<Vec<(Literal, Literal)>>>),
+    // Distinct hashable values in insertion order; set operators build new sets
+    Set(Rc<Vec<Literal>>),
+    Error(Rc<ErrorValue>),
+    Namespace(Rc<Namespace>),
+    Enum(Rc<EnumType>),
+    Variant(Rc<Variant>),
+    Generator(Rc<Generator>),
 }
 
 impl Literal {
+    pub fn new_list(items: Vec<Literal>) -> Self {
+        Literal::List(Rc::new(RefCell::new(items)))
+    }
+
+    pub fn new_tuple(items: Vec<Literal>) -> Self {
+        Literal::Tuple(Rc::new(items))
+    }
+
+    pub fn new_map(entries: Vec<(Literal, Literal)>) -> Self {
+        Literal::Map(Rc::new(RefCell::new(entries)))
+    }
+
+    // Callers are expected to have removed duplicates already
+    pub fn new_set(items: Vec<Literal>) -> Self {
+        Literal::Set(Rc::new(items))
+    }
+
+    // The name of the value's type, for error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Literal::StringLiteral(_) => "string",
+            Literal::NumberLiteral(_) => "number",
+            Literal::BooleanLiteral(_) => "boolean",
+            Literal::NullLiteral => "null",
+            Literal::Callable(_) => "function",
+            Literal::List(_) => "list",
+            Literal::Tuple(_) => "tuple",
+            Literal::Map(_) => "map",
+            Literal::Set(_) => "set",
+            Literal::Error(_) => "error",
+            Literal::Namespace(_) => "module",
+            Literal::Enum(_) => "enum",
+            Literal::Variant(_) => "variant",
+            Literal::Generator(_) => "generator",
+        }
+    }
+
     pub fn as_callable(&self) -> Option<&dyn Callable> {
         if let Literal::Callable(ref callable) = self {
             Some(callable.as_ref())
@@ -78,6 +133,59 @@ impl fmt::Display for Literal {
             Self::NumberLiteral(num) => write!(f, "{}", num),
             Self::StringLiteral(str) => write!(f, "{}", str),
             Self::Callable(c) => write!(f, "{:?}", c),
+            Self::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Self::Tuple(items) => {
+                write!(f, "(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                // A one-element tuple keeps its comma so it reads back as a tuple
+                if items.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            Self::Map(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", key, value)?;
+                }
+                write!(f, "}}")
+            }
+            Self::Set(items) => {
+                // `{}` is an empty map, so an empty set is written as the literal that builds it
+                if items.is_empty() {
+                    return write!(f, "{{...[]}}");
+                }
+                write!(f, "{{")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "}}")
+            }
+            Self::Error(error) => write!(f, "{}", error),
+            Self::Namespace(namespace) => write!(f, "{}", namespace),
+            Self::Enum(enum_type) => write!(f, "{}", enum_type),
+            Self::Variant(variant) => write!(f, "{}", variant),
+            Self::Generator(generator) => write!(f, "{}", generator),
         }
     }
 }
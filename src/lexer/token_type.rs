@@ -22,12 +22,30 @@ pub enum TokenType {
     CLASS,
     THIS,
     SUPER,
+    MATCH,
+    THROW,
+    TRY,
+    CATCH,
+    FINALLY,
+    IMPORT,
+    AS,
+    EXPORT,
+    CONST,
+    DO,
+    LOOP,
+    BREAK,
+    CONTINUE,
+    IN,
+    ENUM,
+    YIELD,
 
     //Characters - Single
     LEFTPAREN,
     RIGHTPAREN,
     LEFTBRACE,
     RIGHTBRACE,
+    LEFTBRACKET,
+    RIGHTBRACKET,
     COMMA,
     DOT,
     MINUS,
@@ -35,6 +53,11 @@ pub enum TokenType {
     SEMICOLON,
     SLASH,
     STAR,
+    COLON,
+    PERCENT,
+    QUESTION,
+    PIPE,
+    AMPERSAND,
 
     //Characters - Double
     BANG,
@@ -45,6 +68,21 @@ pub enum TokenType {
     GREATEREQUAL,
     LESS,
     LESSEQUAL,
+    PLUSEQUAL,
+    MINUSEQUAL,
+    STAREQUAL,
+    SLASHEQUAL,
+    PERCENTEQUAL,
+    PLUSPLUS,
+    MINUSMINUS,
+    QUESTIONQUESTION,
+    QUESTIONDOT,
+    DOTDOT,
+    FATARROW,
+
+    //Characters - Triple
+    DOTDOTDOT,
+    DOTDOTEQUAL,
 
     EOF
 }
\ No newline at end of file
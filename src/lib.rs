@@ -1,7 +1,14 @@
+// Each stage lives in a module named after it (`lexer::lexer`, `parser::parser`, ...)
+#![allow(clippy::module_inception)]
+// Statement lists are `Vec<Box<Stmt>>` throughout the syntax tree, and the code that hands them on keeps that type
+#![allow(clippy::vec_box)]
+
 pub mod lexer;
 pub mod parser;
+pub mod resolver;
 pub mod interpreter;
 
 pub use lexer::Lexer;
 pub use parser::Parser;
+pub use resolver::Resolver;
 pub use interpreter::Interpreter;
\ No newline at end of file
@@ -1,20 +1,36 @@
-use std::{env, fs::File, io::Read, process};
+use std::{env, fs::File, io::Read, path::Path, process};
 
-use neplat::{Interpreter, Lexer, Parser};
+use neplat::{interpreter::files::FileAccess, Interpreter, Lexer, Parser, Resolver};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    match args.len() {
-        2 => run(&args[1]),
+    match args.as_slice() {
+        [_, file_name] => run(file_name, None),
+        [_, flag, root, file_name] if flag == "--root" => run(file_name, Some(root.as_str())),
         _ => {
-            eprintln!("Usage: neplat [script]");
+            eprintln!("Usage: neplat [--root directory] [script]");
             process::exit(1);
         }
     }
 }
 
-fn run(file_name: &str) {
+// Scripts may use the files under `root`, and no files at all when no root is given
+fn file_access(root: Option<&str>) -> FileAccess {
+    let Some(root) = root else {
+        return FileAccess::denied();
+    };
+
+    match FileAccess::new(Path::new(root)) {
+        Ok(access) => access,
+        Err(e) => {
+            eprintln!("Error: cannot use '{}' as the root directory: {}", root, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(file_name: &str, root: Option<&str>) {
     match File::open(file_name) {
         Ok(mut file) => {
             let mut contents = String::new();
@@ -44,11 +60,25 @@ fn run(file_name: &str) {
                             process::exit(1);
                     }
 
+                    // Resolving
+                    let mut resolver = Resolver::new();
+                    resolver.resolve(&statements);
+
+                    if !resolver.get_errors().is_empty() {
+                        eprintln!("Resolve Errors encountered: ");
+                        for error in resolver.get_errors() {
+                            eprintln!("\t{}", error);
+                        }
+                        process::exit(1);
+                    }
+
                     let mut interpreter = Interpreter::new();
-                    if let Err(errors) = interpreter.interpret(&statements) {
+                    interpreter.set_script_path(Path::new(file_name));
+                    interpreter.set_file_access(file_access(root));
+                    if interpreter.interpret(&statements).is_err() {
                         eprintln!("Runtime Errors encountered: ");
-                        for error in errors {
-                            eprintln!("\t{}", error);
+                        for error in interpreter.get_uncaught_errors() {
+                            eprintln!("\t{}", error.stack_trace());
                         }
                     }
                     
@@ -2,31 +2,99 @@ use core::fmt;
 
 use crate::lexer::{Literal, Token};
 
-use super::visitor::ExprVisitor;
+use super::{pattern::Pattern, visitor::ExprVisitor};
 
 #[derive(Debug, Clone)]
 pub enum Expr {
     Assign(Token, Box<Expr>),
+    // name, operator, value
+    CompoundAssign(Token, Token, Box<Expr>),
+    // name, operator, is prefix
+    Update(Token, Token, bool),
     Binary(Box<Expr>, Token, Box<Expr>),
     Grouping(Box<Expr>),
     Literal(Literal),
     Unary(Token, Box<Expr>),
     Variable(Token),
     Logical(Box<Expr>, Token, Box<Expr>),
-    Call(Box<Expr>, Token, Vec<Box<Expr>>),
+    // callee, closing paren, positional arguments, named arguments, optional (`?.()`)
+    Call(Box<Expr>, Token, Vec<Box<Expr>>, Vec<(Token, Box<Expr>)>, bool),
+    // condition, then, else
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+    List(Vec<Box<Expr>>),
+    // `(a, b)`; a single element needs a trailing comma: `(a,)`
+    Tuple(Vec<Box<Expr>>),
+    // (key, value) pairs of a `{naam: "Ram", [key]: value}` literal
+    Map(Vec<(Box<Expr>, Box<Expr>)>),
+    // elements of a `{a, b, ...rest}` literal, each flagged when it is spread
+    Set(Vec<(Box<Expr>, bool)>),
+    // object, opening bracket, index
+    Index(Box<Expr>, Token, Box<Expr>),
+    // object, opening bracket, index, value
+    SetIndex(Box<Expr>, Token, Box<Expr>, Box<Expr>),
+    // object, opening bracket, index, operator, value; `m.total += 1` is read as `m["total"] += 1`
+    CompoundSetIndex(Box<Expr>, Token, Box<Expr>, Token, Box<Expr>),
+    // object, opening bracket, index, operator, is prefix
+    UpdateIndex(Box<Expr>, Token, Box<Expr>, Token, bool),
+    // target pattern, `=` token, value; e.g. `[a, b] = [b, a]`
+    Destructure(Pattern, Token, Box<Expr>),
+    // object, property name, optional (`?.name`)
+    Get(Box<Expr>, Token, bool),
 }
 
 impl Expr {
     pub fn accept<T>(&self, visitor: &mut dyn ExprVisitor<T>) -> T {
         match self {
             Expr::Assign(_token, _expr) => visitor.visit_assign_expression(self),
+            Expr::CompoundAssign(_token, _operator, _expr) => visitor.visit_compound_assign_expression(self),
+            Expr::Update(_token, _operator, _prefix) => visitor.visit_update_expression(self),
             Expr::Binary(_left, _operator, _right) => visitor.visit_binary_expression(self),
             Expr::Grouping(_group) => visitor.visit_grouping_expression(self),
             Expr::Literal(_value) => visitor.visit_literal_expr(self),
             Expr::Unary(_operator, _operand) => visitor.visit_unary_expr(self),
             Expr::Variable(_token) => visitor.vist_variable_expr(self),
             Expr::Logical(_left, _operator, _right) => visitor.visit_logical_expression(self),
-            Expr::Call(_callee, _token, _args) => visitor.visit_call_expression(self),
+            Expr::Call(_callee, _token, _args, _named, _optional) => visitor.visit_call_expression(self),
+            Expr::Conditional(_condition, _then, _else) => visitor.visit_conditional_expression(self),
+            Expr::List(_elements) => visitor.visit_list_expression(self),
+            Expr::Tuple(_elements) => visitor.visit_tuple_expression(self),
+            Expr::Map(_entries) => visitor.visit_map_expression(self),
+            Expr::Set(_elements) => visitor.visit_set_expression(self),
+            Expr::Index(_object, _bracket, _index) => visitor.visit_index_expression(self),
+            Expr::SetIndex(_object, _bracket, _index, _value) => visitor.visit_set_index_expression(self),
+            Expr::CompoundSetIndex(_object, _bracket, _index, _operator, _value) => {
+                visitor.visit_compound_set_index_expression(self)
+            }
+            Expr::UpdateIndex(_object, _bracket, _index, _operator, _prefix) => visitor.visit_update_index_expression(self),
+            Expr::Destructure(_pattern, _equals, _value) => visitor.visit_destructure_expression(self),
+            Expr::Get(_object, _name, _optional) => visitor.visit_get_expression(self),
+        }
+    }
+
+    // Source line of the expression, when it carries a token
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Expr::Assign(token, _)
+            | Expr::CompoundAssign(token, _, _)
+            | Expr::Update(token, _, _)
+            | Expr::Binary(_, token, _)
+            | Expr::Unary(token, _)
+            | Expr::Variable(token)
+            | Expr::Logical(_, token, _)
+            | Expr::Call(_, token, _, _, _)
+            | Expr::Get(_, token, _)
+            | Expr::Destructure(_, token, _)
+            | Expr::Index(_, token, _)
+            | Expr::SetIndex(_, token, _, _)
+            | Expr::CompoundSetIndex(_, token, _, _, _)
+            | Expr::UpdateIndex(_, token, _, _, _) => Some(*token.line()),
+            Expr::Grouping(_)
+            | Expr::Literal(_)
+            | Expr::Conditional(_, _, _)
+            | Expr::List(_)
+            | Expr::Tuple(_)
+            | Expr::Map(_)
+            | Expr::Set(_) => None,
         }
     }
 }
@@ -39,6 +107,18 @@ impl fmt::Display for Expr {
                 write!(f, "{} {}", expr, token.lexeme())
             }
 
+            Expr::CompoundAssign(token, operator, expr) => {
+                write!(f, "{} {} {}", token.lexeme(), operator.lexeme(), expr)
+            }
+
+            Expr::Update(token, operator, prefix) => {
+                if *prefix {
+                    write!(f, "{}{}", operator.lexeme(), token.lexeme())
+                } else {
+                    write!(f, "{}{}", token.lexeme(), operator.lexeme())
+                }
+            }
+
             // Display binary expressions in the format "(left operator right)"
             Expr::Binary(left, operator, right) => {
                 write!(f, "{} {} {}", left, operator.lexeme(), right)
@@ -57,6 +137,7 @@ impl fmt::Display for Expr {
                     Literal::StringLiteral(s) => write!(f, "\"{}\"", s),
                     Literal::NullLiteral => write!(f, "null"),
                     Literal::Callable(c) => write!(f, "{:?}", c),
+                    Literal::List(_) | Literal::Tuple(_) | Literal::Map(_) | Literal::Set(_) | Literal::Error(_) | Literal::Namespace(_) | Literal::Enum(_) | Literal::Variant(_) | Literal::Generator(_) => write!(f, "{}", literal),
                 }
             }
 
@@ -75,8 +156,102 @@ impl fmt::Display for Expr {
             }
 
             // Display call expressions
-            Expr::Call(callee, operator, args) => {
-                write!(f, "{} {} {:?}", callee, operator.lexeme(), args)
+            Expr::Call(callee, operator, args, named, optional) => {
+                if *optional {
+                    write!(f, "?.")?;
+                }
+                write!(f, "{} {} {:?} {:?}", callee, operator.lexeme(), args, named)
+            }
+
+            // Display conditional expressions in the format "condition ? then : else"
+            Expr::Conditional(condition, then_expr, else_expr) => {
+                write!(f, "{} ? {} : {}", condition, then_expr, else_expr)
+            }
+
+            // Display property access in the format "object.name"
+            Expr::Get(object, name, optional) => {
+                if *optional {
+                    write!(f, "{}?.{}", object, name.lexeme())
+                } else {
+                    write!(f, "{}.{}", object, name.lexeme())
+                }
+            }
+
+            // Display list expressions in the format "[a, b]"
+            Expr::List(elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            }
+
+            // Display map expressions in the format "{key: value}"
+            Expr::Map(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", key, value)?;
+                }
+                write!(f, "}}")
+            }
+
+            // Display set expressions in the format "{a, ...rest}"
+            Expr::Set(elements) => {
+                write!(f, "{{")?;
+                for (i, (element, spread)) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    if *spread {
+                        write!(f, "...")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "}}")
+            }
+
+            // Display tuple expressions in the format "(a, b)"
+            Expr::Tuple(elements) => {
+                write!(f, "(")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, ")")
+            }
+
+            // Display index expressions in the format "object[index]"
+            Expr::Index(object, _bracket, index) => {
+                write!(f, "{}[{}]", object, index)
+            }
+
+            Expr::SetIndex(object, _bracket, index, value) => {
+                write!(f, "{}[{}] = {}", object, index, value)
+            }
+
+            Expr::CompoundSetIndex(object, _bracket, index, operator, value) => {
+                write!(f, "{}[{}] {} {}", object, index, operator.lexeme(), value)
+            }
+
+            Expr::UpdateIndex(object, _bracket, index, operator, prefix) => {
+                if *prefix {
+                    write!(f, "{}{}[{}]", operator.lexeme(), object, index)
+                } else {
+                    write!(f, "{}[{}]{}", object, index, operator.lexeme())
+                }
+            }
+
+            // Display destructuring in the format "pattern = value"
+            Expr::Destructure(pattern, _equals, value) => {
+                write!(f, "{} = {}", pattern, value)
             }
         }
     }
@@ -1,6 +1,7 @@
 pub mod parser;
 pub mod expr;
 pub mod stmt;
+pub mod pattern;
 pub mod visitor;
 pub mod parser_error;
 
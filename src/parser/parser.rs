@@ -1,6 +1,6 @@
 use crate::lexer::{Literal, Token, TokenType};
 
-use super::{expr::Expr, parser_error::ParserError, stmt::Stmt};
+use super::{expr::Expr, parser_error::ParserError, pattern::Pattern, stmt::{MatchArm, Param, Stmt}};
 
 pub struct Parser<'a> {
     current: usize,
@@ -39,6 +39,18 @@ impl<'a> Parser<'a> {
                 return self.var_declaration();
             }
 
+            if self.match_token_types(&[TokenType::CONST]) {
+                return self.const_declaration();
+            }
+
+            if self.match_token_types(&[TokenType::EXPORT]) {
+                return self.export_declaration();
+            }
+
+            if self.match_token_types(&[TokenType::ENUM]) {
+                return self.enum_declaration();
+            }
+
             return self.statement();
         }
 
@@ -47,6 +59,10 @@ impl<'a> Parser<'a> {
     }
 
     fn statement(&mut self) -> Option<Stmt> {
+        if self.check(&TokenType::IF) && self.nepali_conditional_ahead() {
+            return self.expression_statement();
+        }
+
         if self.match_token_types(&[TokenType::IF]) {
             return self.if_statement();
         }
@@ -67,6 +83,19 @@ impl<'a> Parser<'a> {
             return self.for_statement();
         }
 
+        if self.match_token_types(&[TokenType::DO]) {
+            return self.do_while_statement();
+        }
+
+        if self.match_token_types(&[TokenType::LOOP]) {
+            let body = self.statement()?;
+            return Some(Stmt::Loop(Box::new(body)));
+        }
+
+        if self.match_token_types(&[TokenType::BREAK, TokenType::CONTINUE]) {
+            return self.loop_control_statement();
+        }
+
         if self.match_token_types(&[TokenType::FUNC]) {
             return self.function_statement("function");
         }
@@ -75,9 +104,464 @@ impl<'a> Parser<'a> {
             return self.return_statement();
         }
 
+        if self.match_token_types(&[TokenType::YIELD]) {
+            return self.yield_statement();
+        }
+
+        if self.match_token_types(&[TokenType::MATCH]) {
+            return self.match_statement();
+        }
+
+        if self.match_token_types(&[TokenType::THROW]) {
+            return self.throw_statement();
+        }
+
+        if self.match_token_types(&[TokenType::TRY]) {
+            return self.try_statement();
+        }
+
+        if self.match_token_types(&[TokenType::IMPORT]) {
+            return self.import_statement();
+        }
+
         self.expression_statement()
     }
 
+    fn import_statement(&mut self) -> Option<Stmt> {
+        let keyword = self.previous().clone();
+
+        if !self.check(&TokenType::STRING) {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect module path string after 'import'.".to_string(),
+            });
+            return None;
+        }
+        let path = self.advance().clone();
+
+        if self.consume(&TokenType::AS).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect 'as' after module path.".to_string(),
+            });
+            return None;
+        }
+
+        let alias = self.consume(&TokenType::IDENTIFIER).cloned();
+        if alias.is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect module name after 'as'.".to_string(),
+            });
+            return None;
+        }
+
+        if self.consume(&TokenType::SEMICOLON).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ';' after import.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Import(keyword, path, alias?))
+    }
+
+    fn export_declaration(&mut self) -> Option<Stmt> {
+        let declaration = if self.match_token_types(&[TokenType::VAR]) {
+            self.var_declaration()?
+        } else if self.match_token_types(&[TokenType::CONST]) {
+            self.const_declaration()?
+        } else if self.match_token_types(&[TokenType::FUNC]) {
+            self.function_statement("function")?
+        } else if self.match_token_types(&[TokenType::ENUM]) {
+            self.enum_declaration()?
+        } else {
+            self.errors.push(ParserError::InvalidDecleration {
+                line: *self.peek().line(),
+                lexeme: "Only 'let', 'const', 'func' and 'enum' declarations can be exported.".to_string(),
+            });
+            return None;
+        };
+
+        Some(Stmt::Export(Box::new(declaration)))
+    }
+
+    // `enum Shape { Circle(radius), Square(side), Empty }`
+    fn enum_declaration(&mut self) -> Option<Stmt> {
+        let name = self.consume(&TokenType::IDENTIFIER).cloned();
+        let Some(name) = name else {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect enum name.".to_string(),
+            });
+            return None;
+        };
+
+        if self.consume(&TokenType::LEFTBRACE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '{' after enum name.".to_string(),
+            });
+            return None;
+        }
+
+        let mut variants: Vec<(Token, Vec<Token>)> = Vec::new();
+        while !self.check(&TokenType::RIGHTBRACE) && !self.is_at_end() {
+            let variant = self.consume(&TokenType::IDENTIFIER).cloned()?;
+            if variants.iter().any(|(existing, _)| existing.lexeme() == variant.lexeme()) {
+                self.errors.push(ParserError::InvalidDecleration {
+                    line: *variant.line(),
+                    lexeme: format!("Variant '{}' is declared more than once.", variant.lexeme()),
+                });
+                return None;
+            }
+
+            let mut fields: Vec<Token> = Vec::new();
+            if self.match_token_types(&[TokenType::LEFTPAREN]) {
+                loop {
+                    fields.push(self.consume(&TokenType::IDENTIFIER).cloned()?);
+                    if !self.match_token_types(&[TokenType::COMMA]) {
+                        break;
+                    }
+                }
+                self.consume(&TokenType::RIGHTPAREN)?;
+            }
+            variants.push((variant, fields));
+
+            if !self.match_token_types(&[TokenType::COMMA]) {
+                break;
+            }
+        }
+
+        if self.consume(&TokenType::RIGHTBRACE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '}' after enum variants.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Enum(name, variants))
+    }
+
+    fn throw_statement(&mut self) -> Option<Stmt> {
+        let keyword = self.previous().clone();
+        let value = self.expression()?;
+
+        if self.consume(&TokenType::SEMICOLON).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ';' after thrown value.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Throw(keyword, value))
+    }
+
+    fn try_statement(&mut self) -> Option<Stmt> {
+        if self.consume(&TokenType::LEFTBRACE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '{' after 'try'.".to_string(),
+            });
+            return None;
+        }
+        let body = self.block();
+
+        let mut catch_name: Option<Token> = None;
+        let mut catch_body: Option<Vec<Box<Stmt>>> = None;
+        if self.match_token_types(&[TokenType::CATCH]) {
+            if self.match_token_types(&[TokenType::LEFTPAREN]) {
+                catch_name = Some(self.consume(&TokenType::IDENTIFIER).cloned()?);
+
+                if self.consume(&TokenType::RIGHTPAREN).is_none() {
+                    self.errors.push(ParserError::ExpectedExpression {
+                        line: *self.peek().line(),
+                        lexeme: "Expect ')' after catch name.".to_string(),
+                    });
+                    return None;
+                }
+            }
+
+            if self.consume(&TokenType::LEFTBRACE).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect '{' before catch body.".to_string(),
+                });
+                return None;
+            }
+            catch_body = Some(self.block());
+        }
+
+        let mut finally_body: Option<Vec<Box<Stmt>>> = None;
+        if self.match_token_types(&[TokenType::FINALLY]) {
+            if self.consume(&TokenType::LEFTBRACE).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect '{' before finally body.".to_string(),
+                });
+                return None;
+            }
+            finally_body = Some(self.block());
+        }
+
+        if catch_body.is_none() && finally_body.is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect 'catch' or 'finally' after try block.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Try(body, catch_name, catch_body, finally_body))
+    }
+
+    fn match_statement(&mut self) -> Option<Stmt> {
+        let keyword = self.previous().clone();
+
+        if self.consume(&TokenType::LEFTPAREN).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '(' after 'match'.".to_string(),
+            });
+            return None;
+        }
+
+        let subject = self.expression()?;
+
+        if self.consume(&TokenType::RIGHTPAREN).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ')' after 'match' value.".to_string(),
+            });
+            return None;
+        }
+
+        if self.consume(&TokenType::LEFTBRACE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '{' before match arms.".to_string(),
+            });
+            return None;
+        }
+
+        let mut arms: Vec<MatchArm> = Vec::new();
+        while !self.check(&TokenType::RIGHTBRACE) && !self.is_at_end() {
+            let pattern = self.pattern()?;
+
+            let guard = if self.match_token_types(&[TokenType::IF]) {
+                Some(self.expression()?)
+            } else {
+                None
+            };
+
+            if self.consume(&TokenType::FATARROW).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect '=>' after match pattern.".to_string(),
+                });
+                return None;
+            }
+
+            let body = Box::new(self.statement()?);
+            arms.push(MatchArm::new(pattern, guard, body));
+        }
+
+        if self.consume(&TokenType::RIGHTBRACE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '}' after match arms.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Match(keyword, subject, arms))
+    }
+
+    fn pattern(&mut self) -> Option<Pattern> {
+        if self.match_token_types(&[TokenType::LEFTBRACKET]) {
+            let mut elements: Vec<Pattern> = Vec::new();
+            let mut rest: Option<Box<Pattern>> = None;
+
+            if !self.check(&TokenType::RIGHTBRACKET) {
+                loop {
+                    if self.match_token_types(&[TokenType::DOTDOTDOT]) {
+                        rest = Some(Box::new(self.pattern()?));
+                        break;
+                    }
+
+                    elements.push(self.pattern()?);
+
+                    if !self.match_token_types(&[TokenType::COMMA]) {
+                        break;
+                    }
+                }
+            }
+
+            if self.consume(&TokenType::RIGHTBRACKET).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect ']' after list pattern.".to_string(),
+                });
+                return None;
+            }
+
+            return Some(Pattern::List(elements, rest));
+        }
+
+        if self.match_token_types(&[TokenType::LEFTPAREN]) {
+            let mut elements: Vec<Pattern> = Vec::new();
+            // `(p)` is just `p`; a comma makes it a tuple pattern
+            let mut is_tuple = self.check(&TokenType::RIGHTPAREN);
+
+            while !self.check(&TokenType::RIGHTPAREN) && !self.is_at_end() {
+                elements.push(self.pattern()?);
+
+                if !self.match_token_types(&[TokenType::COMMA]) {
+                    break;
+                }
+                is_tuple = true;
+            }
+
+            if self.consume(&TokenType::RIGHTPAREN).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect ')' after tuple pattern.".to_string(),
+                });
+                return None;
+            }
+
+            if !is_tuple {
+                return elements.pop();
+            }
+            return Some(Pattern::Tuple(elements));
+        }
+
+        if self.match_token_types(&[TokenType::LEFTBRACE]) {
+            let mut entries: Vec<(Token, Pattern)> = Vec::new();
+
+            if !self.check(&TokenType::RIGHTBRACE) {
+                loop {
+                    let key = self.map_key()?;
+
+                    let value = if self.match_token_types(&[TokenType::COLON]) {
+                        self.pattern()?
+                    } else if key.token_type() == &TokenType::IDENTIFIER {
+                        Pattern::Binding(key.clone())
+                    } else {
+                        self.errors.push(ParserError::ExpectedExpression {
+                            line: *key.line(),
+                            lexeme: "Expect ':' after quoted key in map pattern.".to_string(),
+                        });
+                        return None;
+                    };
+                    entries.push((key, value));
+
+                    if !self.match_token_types(&[TokenType::COMMA]) {
+                        break;
+                    }
+                }
+            }
+
+            if self.consume(&TokenType::RIGHTBRACE).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect '}' after map pattern.".to_string(),
+                });
+                return None;
+            }
+
+            return Some(Pattern::Map(entries));
+        }
+
+        if self.match_token_types(&[TokenType::IDENTIFIER]) {
+            let name = self.previous().clone();
+            if self.check(&TokenType::DOT) {
+                return self.variant_pattern(name);
+            }
+            if name.lexeme() == "_" {
+                return Some(Pattern::Wildcard);
+            }
+            return Some(Pattern::Binding(name));
+        }
+
+        if self.match_token_types(&[TokenType::TRUE]) {
+            return Some(Pattern::Literal(Literal::BooleanLiteral(true)));
+        }
+
+        if self.match_token_types(&[TokenType::FALSE]) {
+            return Some(Pattern::Literal(Literal::BooleanLiteral(false)));
+        }
+
+        if self.match_token_types(&[TokenType::NULL]) {
+            return Some(Pattern::Literal(Literal::NullLiteral));
+        }
+
+        if self.match_token_types(&[TokenType::STRING]) {
+            return self.previous().literal().clone().map(Pattern::Literal);
+        }
+
+        let start = self.pattern_number()?;
+
+        if self.match_token_types(&[TokenType::DOTDOT, TokenType::DOTDOTEQUAL]) {
+            let inclusive = self.previous().token_type() == &TokenType::DOTDOTEQUAL;
+            let end = self.pattern_number()?;
+            return Some(Pattern::Range(start, end, inclusive));
+        }
+
+        Some(Pattern::Literal(Literal::NumberLiteral(start)))
+    }
+
+    // `Color.Rato` or `Shape.Circle(r)`, after the first name of the path
+    fn variant_pattern(&mut self, first: Token) -> Option<Pattern> {
+        let mut path = vec![first];
+        while self.match_token_types(&[TokenType::DOT]) {
+            path.push(self.consume(&TokenType::IDENTIFIER).cloned()?);
+        }
+        let variant = path.pop()?;
+
+        let fields = if self.match_token_types(&[TokenType::LEFTPAREN]) {
+            let mut fields: Vec<Pattern> = Vec::new();
+            while !self.check(&TokenType::RIGHTPAREN) && !self.is_at_end() {
+                fields.push(self.pattern()?);
+                if !self.match_token_types(&[TokenType::COMMA]) {
+                    break;
+                }
+            }
+
+            if self.consume(&TokenType::RIGHTPAREN).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect ')' after variant fields.".to_string(),
+                });
+                return None;
+            }
+            Some(fields)
+        } else {
+            None
+        };
+
+        Some(Pattern::Variant(path, variant, fields))
+    }
+
+    fn pattern_number(&mut self) -> Option<f64> {
+        let negative = self.match_token_types(&[TokenType::MINUS]);
+
+        if self.match_token_types(&[TokenType::NUMBER]) {
+            if let Some(Literal::NumberLiteral(number)) = self.previous().literal() {
+                return Some(if negative { -number } else { *number });
+            }
+        }
+
+        self.errors.push(ParserError::ExpectedExpression {
+            line: *self.peek().line(),
+            lexeme: format!("Expect pattern, found '{}'.", self.peek().lexeme()),
+        });
+        None
+    }
+
     fn return_statement(&mut self) -> Option<Stmt> {
         let keyword = self.previous().clone();
 
@@ -88,7 +572,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::SEMICOLON).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expect ';' after return value.".to_string(),
             });
             return None;
@@ -97,11 +581,30 @@ impl<'a> Parser<'a> {
         Some(Stmt::Return(keyword, value))
     }
 
+    fn yield_statement(&mut self) -> Option<Stmt> {
+        let keyword = self.previous().clone();
+
+        let mut value: Option<Expr> = None;
+        if !self.check(&TokenType::SEMICOLON) {
+            value = Some(self.expression()?);
+        }
+
+        if self.consume(&TokenType::SEMICOLON).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ';' after yielded value.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Yield(keyword, value))
+    }
+
     fn function_statement(&mut self, kind: &str) -> Option<Stmt> {
         let name = self.consume(&TokenType::IDENTIFIER).cloned();
         if name.is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: format!("Expected {} name.", kind),
             });
             return None;
@@ -109,29 +612,58 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::LEFTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: format!("Expected '(' after {} name.", kind),
             });
             return None;
         }
 
-        let mut parameters: Vec<Token> = Vec::new();
+        let mut parameters: Vec<Param> = Vec::new();
         if !self.check(&TokenType::RIGHTPAREN) {
             loop {
                 if parameters.len() >= 255 {
                     self.errors.push(ParserError::MaxFunctionArguments {
-                        line: self.peek().line().clone(),
+                        line: *self.peek().line(),
                         lexeme: "Cannot have more than 255 arguments.".to_string(),
                     });
                     return None;
                 }
 
-                if let Some(parameter) = self.consume(&TokenType::IDENTIFIER).cloned() {
-                    parameters.push(parameter);
+                if parameters.last().is_some_and(|param| param.rest) {
+                    self.errors.push(ParserError::InvalidDecleration {
+                        line: *self.peek().line(),
+                        lexeme: "Rest parameter must be the last parameter.".to_string(),
+                    });
+                    return None;
+                }
+
+                let rest = self.match_token_types(&[TokenType::DOTDOTDOT]);
+
+                let name = self.consume(&TokenType::IDENTIFIER).cloned()?;
+
+                let default = if self.match_token_types(&[TokenType::EQUAL]) {
+                    if rest {
+                        self.errors.push(ParserError::InvalidDecleration {
+                            line: *name.line(),
+                            lexeme: "Rest parameter cannot have a default value.".to_string(),
+                        });
+                        return None;
+                    }
+                    Some(self.expression()?)
                 } else {
+                    None
+                };
+
+                if !rest && default.is_none() && parameters.iter().any(|param| param.default.is_some()) {
+                    self.errors.push(ParserError::InvalidDecleration {
+                        line: *name.line(),
+                        lexeme: "Parameter without default cannot follow a parameter with default.".to_string(),
+                    });
                     return None;
                 }
 
+                parameters.push(Param::new(name, default, rest));
+
                 if !self.match_token_types(&[TokenType::COMMA]) {
                     break;
                 }
@@ -140,7 +672,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::RIGHTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expected ')' after parameters".to_string(),
             });
             return None;
@@ -148,7 +680,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::LEFTBRACE).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: format!("Expect '{{' before {} body.", kind),
             });
             return None;
@@ -163,12 +695,23 @@ impl<'a> Parser<'a> {
     fn for_statement(&mut self) -> Option<Stmt> {
         if self.consume(&TokenType::LEFTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expect '(' after 'for'.".to_string(),
             });
             return None;
         }
 
+        // `for (x in items)` or `for (let x in items)` walks a collection
+        let declares = self.check(&TokenType::VAR);
+        let name_offset = if declares { 1 } else { 0 };
+        let is_for_in = self
+            .tokens
+            .get(self.current + name_offset + 1)
+            .is_some_and(|token| token.token_type() == &TokenType::IN);
+        if is_for_in && self.tokens[self.current + name_offset].token_type() == &TokenType::IDENTIFIER {
+            return self.for_in_statement(declares);
+        }
+
         let initializer = if self.match_token_types(&[TokenType::SEMICOLON]) {
             None
         } else if self.match_token_types(&[TokenType::VAR]) {
@@ -185,7 +728,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::SEMICOLON).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expect ';' after loop condition.".to_string(),
             });
             return None;
@@ -199,7 +742,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::RIGHTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expect ')' after for clauses.".to_string(),
             });
             return None;
@@ -207,15 +750,11 @@ impl<'a> Parser<'a> {
 
         let mut body = self.statement()?;
 
-        // If increment is present, execute it after the body
-        if let Some(increment) = increment {
-            body = Stmt::Block(vec![Box::new(body), Box::new(Stmt::Expression(increment))]);
-        }
-
         // If no condition is present, assume `true` (infinite loop)
         let condition = condition.unwrap_or(Expr::Literal(Literal::BooleanLiteral(true)));
-        // Wrap the body in a while loop using the condition
-        body = Stmt::While(condition, Box::new(body));
+        // Wrap the body in a while loop using the condition; the increment runs after
+        // every iteration, including one cut short by `continue`
+        body = Stmt::While(condition, Box::new(body), increment);
 
         // If initializer exists, execute it before the loop
         if let Some(initializer) = initializer {
@@ -225,34 +764,115 @@ impl<'a> Parser<'a> {
         Some(body)
     }
 
+    // The rest of `for (x in items) body` after the opening paren
+    fn for_in_statement(&mut self, declares: bool) -> Option<Stmt> {
+        if declares {
+            self.advance();
+        }
+        let name = self.advance().clone();
+        // Skip the `in`
+        self.advance();
+
+        let iterable = self.expression()?;
+
+        if self.consume(&TokenType::RIGHTPAREN).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ')' after for-in clause.".to_string(),
+            });
+            return None;
+        }
+
+        let body = self.statement()?;
+        Some(Stmt::ForIn(name, iterable, Box::new(body)))
+    }
+
     fn while_statement(&mut self) -> Option<Stmt> {
         if self.consume(&TokenType::LEFTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
+                lexeme: "Expect '(' after 'while'.".to_string(),
+            });
+            return None;
+        }
+
+        let condition = self.expression()?;
+
+        if self.consume(&TokenType::RIGHTPAREN).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ')' after 'while' condition.".to_string(),
+            });
+            return None;
+        }
+
+        let body = Box::new(self.statement()?);
+
+        Some(Stmt::While(condition, body, None))
+    }
+
+    fn do_while_statement(&mut self) -> Option<Stmt> {
+        let body = Box::new(self.statement()?);
+
+        if self.consume(&TokenType::WHILE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect 'while' after 'do' body.".to_string(),
+            });
+            return None;
+        }
+
+        if self.consume(&TokenType::LEFTPAREN).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
                 lexeme: "Expect '(' after 'while'.".to_string(),
             });
             return None;
         }
 
-        let condition = self.expression()?;
+        let condition = self.expression()?;
+
+        if self.consume(&TokenType::RIGHTPAREN).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ')' after 'while' condition.".to_string(),
+            });
+            return None;
+        }
+
+        if self.consume(&TokenType::SEMICOLON).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect ';' after do-while condition.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::DoWhile(body, condition))
+    }
+
+    fn loop_control_statement(&mut self) -> Option<Stmt> {
+        let keyword = self.previous().clone();
 
-        if self.consume(&TokenType::RIGHTPAREN).is_none() {
+        if self.consume(&TokenType::SEMICOLON).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
-                lexeme: "Expect ')' after 'while' condition.".to_string(),
+                line: *self.peek().line(),
+                lexeme: format!("Expect ';' after '{}'.", keyword.lexeme()),
             });
             return None;
         }
 
-        let body = Box::new(self.statement()?);
-
-        Some(Stmt::While(condition, body))
+        if keyword.token_type() == &TokenType::BREAK {
+            Some(Stmt::Break(keyword))
+        } else {
+            Some(Stmt::Continue(keyword))
+        }
     }
 
     fn if_statement(&mut self) -> Option<Stmt> {
         if self.consume(&TokenType::LEFTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expect '(' after 'if'.".to_string(),
             });
             return None;
@@ -262,7 +882,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::RIGHTPAREN).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expect ')' after 'if' condition.".to_string(),
             });
             return None;
@@ -283,7 +903,7 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::SEMICOLON).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expected ';' after value.".to_string(),
             });
             return None;
@@ -293,6 +913,10 @@ impl<'a> Parser<'a> {
     }
 
     fn var_declaration(&mut self) -> Option<Stmt> {
+        if self.check_destructuring() {
+            return self.destructuring_declaration(false);
+        }
+
         if let Some(var_name) = self.consume(&TokenType::IDENTIFIER).cloned() {
             let mut initializer: Option<Expr> = None;
             if self.match_token_types(&[TokenType::EQUAL]) {
@@ -301,7 +925,7 @@ impl<'a> Parser<'a> {
 
             if self.consume(&TokenType::SEMICOLON).is_none() {
                 self.errors.push(ParserError::ExpectedExpression {
-                    line: self.peek().line().clone(),
+                    line: *self.peek().line(),
                     lexeme: "Expected ';' after value.".to_string(),
                 });
                 return None;
@@ -311,18 +935,79 @@ impl<'a> Parser<'a> {
         }
 
         self.errors.push(ParserError::InvalidDecleration {
-            line: self.peek().line().clone(),
+            line: *self.peek().line(),
             lexeme: "Expect variable name.".to_string(),
         });
         None
     }
 
+    fn const_declaration(&mut self) -> Option<Stmt> {
+        if self.check_destructuring() {
+            return self.destructuring_declaration(true);
+        }
+
+        let name = self.consume(&TokenType::IDENTIFIER).cloned();
+        if name.is_none() {
+            self.errors.push(ParserError::InvalidDecleration {
+                line: *self.peek().line(),
+                lexeme: "Expect constant name.".to_string(),
+            });
+            return None;
+        }
+
+        if !self.match_token_types(&[TokenType::EQUAL]) {
+            self.errors.push(ParserError::InvalidDecleration {
+                line: *self.peek().line(),
+                lexeme: "Constants must be initialized.".to_string(),
+            });
+            return None;
+        }
+        let initializer = self.expression()?;
+
+        if self.consume(&TokenType::SEMICOLON).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expected ';' after value.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Const(name?, initializer))
+    }
+
+    fn check_destructuring(&self) -> bool {
+        self.check(&TokenType::LEFTBRACKET) || self.check(&TokenType::LEFTBRACE) || self.check(&TokenType::LEFTPAREN)
+    }
+
+    fn destructuring_declaration(&mut self, constant: bool) -> Option<Stmt> {
+        let pattern = self.pattern()?;
+
+        if !self.match_token_types(&[TokenType::EQUAL]) {
+            self.errors.push(ParserError::InvalidDecleration {
+                line: *self.peek().line(),
+                lexeme: "Destructuring declarations must be initialized.".to_string(),
+            });
+            return None;
+        }
+        let initializer = self.expression()?;
+
+        if self.consume(&TokenType::SEMICOLON).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expected ';' after value.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Stmt::Destructure(pattern, initializer, constant))
+    }
+
     fn expression_statement(&mut self) -> Option<Stmt> {
         let expr = self.expression()?;
 
         if self.consume(&TokenType::SEMICOLON).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expected ';' after expression.".to_string(),
             });
             return None;
@@ -342,12 +1027,12 @@ impl<'a> Parser<'a> {
 
         if self.consume(&TokenType::RIGHTBRACE).is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expected '}' after block.".to_string(),
             });
         }
 
-        return statements;
+        statements
     }
 
     fn expression(&mut self) -> Option<Expr> {
@@ -355,7 +1040,7 @@ impl<'a> Parser<'a> {
     }
 
     fn assignment(&mut self) -> Option<Expr> {
-        let expr = self.or()?;
+        let expr = self.conditional()?;
 
         if self.match_token_types(&[TokenType::EQUAL]) {
             let equals = self.previous().clone();
@@ -363,13 +1048,156 @@ impl<'a> Parser<'a> {
             if let Some(value) = self.assignment() {
                 if let Expr::Variable(name) = expr {
                     return Some(Expr::Assign(name, Box::new(value)));
+                } else if let Some((object, bracket, index)) = Self::element_target(&expr) {
+                    return Some(Expr::SetIndex(object, bracket, index, Box::new(value)));
+                } else if let Some(pattern) = self.assignment_target(&expr) {
+                    return Some(Expr::Destructure(pattern, equals, Box::new(value)));
                 } else {
                     self.errors.push(ParserError::InvalidAssignment {
-                        line: equals.line().clone(),
+                        line: *equals.line(),
                         lexeme: equals.lexeme().to_string(),
                     });
                 }
             }
+        } else if self.match_token_types(&[
+            TokenType::PLUSEQUAL,
+            TokenType::MINUSEQUAL,
+            TokenType::STAREQUAL,
+            TokenType::SLASHEQUAL,
+            TokenType::PERCENTEQUAL,
+        ]) {
+            let operator = self.previous().clone();
+
+            if let Some(value) = self.assignment() {
+                if let Expr::Variable(name) = expr {
+                    return Some(Expr::CompoundAssign(name, operator, Box::new(value)));
+                } else if let Some((object, bracket, index)) = Self::element_target(&expr) {
+                    return Some(Expr::CompoundSetIndex(object, bracket, index, operator, Box::new(value)));
+                } else {
+                    self.errors.push(ParserError::InvalidAssignment {
+                        line: *operator.line(),
+                        lexeme: operator.lexeme().to_string(),
+                    });
+                }
+            }
+        }
+
+        Some(expr)
+    }
+
+    // `xs[i]` or `m.name` on the left of an assignment, as the object, bracket and index it writes to
+    fn element_target(expr: &Expr) -> Option<(Box<Expr>, Token, Box<Expr>)> {
+        match expr {
+            Expr::Index(object, bracket, index) => Some((object.clone(), bracket.clone(), index.clone())),
+            Expr::Get(object, name, false) => {
+                let key = Expr::Literal(Literal::StringLiteral(name.lexeme().to_string()));
+                Some((object.clone(), name.clone(), Box::new(key)))
+            }
+            _ => None,
+        }
+    }
+
+    // Reads a list or tuple literal on the left of `=` as the pattern it destructures into
+    fn assignment_target(&self, expr: &Expr) -> Option<Pattern> {
+        match expr {
+            Expr::Variable(name) if name.lexeme() == "_" => Some(Pattern::Wildcard),
+            Expr::Variable(name) => Some(Pattern::Binding(name.clone())),
+            Expr::List(elements) => {
+                let targets = elements
+                    .iter()
+                    .map(|element| self.assignment_target(element))
+                    .collect::<Option<Vec<Pattern>>>()?;
+                Some(Pattern::List(targets, None))
+            }
+            Expr::Tuple(elements) => {
+                let targets = elements
+                    .iter()
+                    .map(|element| self.assignment_target(element))
+                    .collect::<Option<Vec<Pattern>>>()?;
+                Some(Pattern::Tuple(targets))
+            }
+            _ => None,
+        }
+    }
+
+    fn conditional(&mut self) -> Option<Expr> {
+        if self.match_token_types(&[TokenType::IF]) {
+            return self.nepali_conditional();
+        }
+
+        let condition = self.coalesce()?;
+
+        if self.match_token_types(&[TokenType::QUESTION]) {
+            let then_branch = self.expression()?;
+
+            if self.consume(&TokenType::COLON).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect ':' after then branch of conditional expression.".to_string(),
+                });
+                return None;
+            }
+
+            let else_branch = self.conditional()?;
+            return Some(Expr::Conditional(
+                Box::new(condition),
+                Box::new(then_branch),
+                Box::new(else_branch),
+            ));
+        }
+
+        Some(condition)
+    }
+
+    // `yadi cond bhane a natra b`; `bhane` and `natra` are only words here, so they stay usable as names elsewhere
+    fn nepali_conditional(&mut self) -> Option<Expr> {
+        let condition = self.coalesce()?;
+
+        if !self.match_word("bhane") {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect 'bhane' after condition.".to_string(),
+            });
+            return None;
+        }
+        let then_branch = self.expression()?;
+
+        if !self.match_word("natra") {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect 'natra' after then branch of conditional expression.".to_string(),
+            });
+            return None;
+        }
+        let else_branch = self.conditional()?;
+
+        Some(Expr::Conditional(
+            Box::new(condition),
+            Box::new(then_branch),
+            Box::new(else_branch),
+        ))
+    }
+
+    // Whether the `yadi` at the current token starts a conditional expression rather than an `if` statement,
+    // found by reading the condition ahead and looking for `bhane` after it
+    fn nepali_conditional_ahead(&mut self) -> bool {
+        let (start, error_count) = (self.current, self.errors.len());
+        self.advance();
+        let found = self.coalesce().is_some() && self.check_word("bhane");
+
+        self.current = start;
+        self.errors.truncate(error_count);
+        found
+    }
+
+    fn coalesce(&mut self) -> Option<Expr> {
+        let mut expr = self.or()?;
+
+        while self.match_token_types(&[TokenType::QUESTIONQUESTION]) {
+            let operator = self.previous().clone();
+            let right = self.or()?;
+
+            expr = Expr::Logical(Box::new(expr), operator, Box::new(right))
         }
 
         Some(expr)
@@ -414,14 +1242,40 @@ impl<'a> Parser<'a> {
     }
 
     fn comparison(&mut self) -> Option<Expr> {
-        let mut expr = self.term()?;
+        let mut expr = self.union()?;
 
         while self.match_token_types(&[
             TokenType::GREATER,
             TokenType::GREATEREQUAL,
             TokenType::LESS,
             TokenType::LESSEQUAL,
+            TokenType::IN,
         ]) {
+            let operator = self.previous().clone();
+            let right = self.union()?;
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+
+        Some(expr)
+    }
+
+    // `a | b`, binding looser than `a & b` as with bitwise operators elsewhere
+    fn union(&mut self) -> Option<Expr> {
+        let mut expr = self.intersection()?;
+
+        while self.match_token_types(&[TokenType::PIPE]) {
+            let operator = self.previous().clone();
+            let right = self.intersection()?;
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+
+        Some(expr)
+    }
+
+    fn intersection(&mut self) -> Option<Expr> {
+        let mut expr = self.term()?;
+
+        while self.match_token_types(&[TokenType::AMPERSAND]) {
             let operator = self.previous().clone();
             let right = self.term()?;
             expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
@@ -433,8 +1287,18 @@ impl<'a> Parser<'a> {
     fn term(&mut self) -> Option<Expr> {
         let mut expr = self.factor()?;
 
-        while self.match_token_types(&[TokenType::MINUS, TokenType::PLUS]) {
+        while self.match_token_types(&[TokenType::MINUS, TokenType::PLUS, TokenType::MINUSMINUS]) {
             let operator = self.previous().clone();
+
+            // `a--b` is `a - -b`; `postfix` leaves a `--` that an operand follows to us
+            if operator.token_type() == &TokenType::MINUSMINUS {
+                let minus = Token::new(TokenType::MINUS, "-", None, *operator.line());
+                let negated = Expr::Unary(minus.clone(), Box::new(self.unary()?));
+                let right = self.factor_rest(negated)?;
+                expr = Expr::Binary(Box::new(expr), minus, Box::new(right));
+                continue;
+            }
+
             let right = self.factor()?;
             expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
         }
@@ -443,9 +1307,13 @@ impl<'a> Parser<'a> {
     }
 
     fn factor(&mut self) -> Option<Expr> {
-        let mut expr = self.unary()?;
+        let expr = self.unary()?;
+        self.factor_rest(expr)
+    }
 
-        while self.match_token_types(&[TokenType::SLASH, TokenType::STAR]) {
+    // The `* / %` operators after a factor's first operand
+    fn factor_rest(&mut self, mut expr: Expr) -> Option<Expr> {
+        while self.match_token_types(&[TokenType::SLASH, TokenType::STAR, TokenType::PERCENT]) {
             let operator = self.previous().clone();
             let right = self.unary()?;
             expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
@@ -461,7 +1329,44 @@ impl<'a> Parser<'a> {
             return Some(Expr::Unary(operator, Box::new(right)));
         }
 
-        self.call()
+        if self.match_token_types(&[TokenType::PLUSPLUS, TokenType::MINUSMINUS]) {
+            let operator = self.previous().clone();
+            let target = self.unary()?;
+            return self.update(target, operator, true);
+        }
+
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> Option<Expr> {
+        let expr = self.call()?;
+
+        // A `--` with an operand after it is a subtraction of a negation, as in `a--b`
+        if self.check(&TokenType::MINUSMINUS) && self.next_begins_operand() {
+            return Some(expr);
+        }
+
+        if self.match_token_types(&[TokenType::PLUSPLUS, TokenType::MINUSMINUS]) {
+            let operator = self.previous().clone();
+            return self.update(expr, operator, false);
+        }
+
+        Some(expr)
+    }
+
+    fn update(&mut self, target: Expr, operator: Token, prefix: bool) -> Option<Expr> {
+        if let Expr::Variable(name) = target {
+            return Some(Expr::Update(name, operator, prefix));
+        }
+        if let Some((object, bracket, index)) = Self::element_target(&target) {
+            return Some(Expr::UpdateIndex(object, bracket, index, operator, prefix));
+        }
+
+        self.errors.push(ParserError::InvalidAssignment {
+            line: *operator.line(),
+            lexeme: operator.lexeme().to_string(),
+        });
+        None
     }
 
     fn call(&mut self) -> Option<Expr> {
@@ -469,9 +1374,33 @@ impl<'a> Parser<'a> {
 
         loop {
             if self.match_token_types(&[TokenType::LEFTPAREN]) {
-                if let Some(exp) = self.finish_call(expr.clone()) {
+                if let Some(exp) = self.finish_call(expr.clone(), false) {
                     expr = exp;
                 }
+            } else if self.match_token_types(&[TokenType::LEFTBRACKET]) {
+                let bracket = self.previous().clone();
+                let index = self.expression()?;
+
+                if self.consume(&TokenType::RIGHTBRACKET).is_none() {
+                    self.errors.push(ParserError::ExpectedExpression {
+                        line: *self.peek().line(),
+                        lexeme: "Expect ']' after index.".to_string(),
+                    });
+                    return None;
+                }
+                expr = Expr::Index(Box::new(expr), bracket, Box::new(index));
+            } else if self.match_token_types(&[TokenType::DOT]) {
+                let name = self.property_name()?;
+                expr = Expr::Get(Box::new(expr), name, false);
+            } else if self.match_token_types(&[TokenType::QUESTIONDOT]) {
+                if self.match_token_types(&[TokenType::LEFTPAREN]) {
+                    if let Some(exp) = self.finish_call(expr.clone(), true) {
+                        expr = exp;
+                    }
+                } else {
+                    let name = self.property_name()?;
+                    expr = Expr::Get(Box::new(expr), name, true);
+                }
             } else {
                 break;
             }
@@ -480,21 +1409,60 @@ impl<'a> Parser<'a> {
         Some(expr)
     }
 
-    fn finish_call(&mut self, callee: Expr) -> Option<Expr> {
+    fn property_name(&mut self) -> Option<Token> {
+        if self.check(&TokenType::IDENTIFIER) {
+            return Some(self.advance().clone());
+        }
+
+        self.errors.push(ParserError::ExpectedExpression {
+            line: *self.peek().line(),
+            lexeme: "Expect property name after '.'.".to_string(),
+        });
+        None
+    }
+
+    fn finish_call(&mut self, callee: Expr, optional: bool) -> Option<Expr> {
         let mut arguments: Vec<Box<Expr>> = Vec::new();
+        let mut named_arguments: Vec<(Token, Box<Expr>)> = Vec::new();
 
         if !self.check(&TokenType::RIGHTPAREN) {
             loop {
-                if arguments.len() >= 255 {
+                if arguments.len() + named_arguments.len() >= 255 {
                     self.errors.push(ParserError::MaxFunctionArguments {
-                        line: self.peek().line().clone(),
+                        line: *self.peek().line(),
                         lexeme: "Cannot have more than 255 arguments.".to_string(),
                     });
                     return None;
                 }
 
-                if let Some(expr) = self.expression() {
-                    arguments.push(Box::new(expr));
+                // A named argument looks like `name: value`
+                if self.check(&TokenType::IDENTIFIER) && self.check_next(&TokenType::COLON) {
+                    let name = self.advance().clone();
+                    self.advance();
+
+                    if named_arguments.iter().any(|(existing, _)| existing.lexeme() == name.lexeme()) {
+                        self.errors.push(ParserError::InvalidDecleration {
+                            line: *name.line(),
+                            lexeme: format!("Duplicate named argument '{}'.", name.lexeme()),
+                        });
+                        return None;
+                    }
+
+                    if let Some(expr) = self.expression() {
+                        named_arguments.push((name, Box::new(expr)));
+                    }
+                } else {
+                    if !named_arguments.is_empty() {
+                        self.errors.push(ParserError::InvalidDecleration {
+                            line: *self.peek().line(),
+                            lexeme: "Positional argument cannot follow a named argument.".to_string(),
+                        });
+                        return None;
+                    }
+
+                    if let Some(expr) = self.expression() {
+                        arguments.push(Box::new(expr));
+                    }
                 }
 
                 if !self.match_token_types(&[TokenType::COMMA]) {
@@ -506,7 +1474,7 @@ impl<'a> Parser<'a> {
         let paren = self.consume(&TokenType::RIGHTPAREN);
         if paren.is_none() {
             self.errors.push(ParserError::ExpectedExpression {
-                line: self.peek().line().clone(),
+                line: *self.peek().line(),
                 lexeme: "Expected ')' after arguments.".to_string(),
             });
             return None;
@@ -516,6 +1484,8 @@ impl<'a> Parser<'a> {
             Box::new(callee),
             paren.unwrap().clone(),
             arguments,
+            named_arguments,
+            optional,
         ))
     }
 
@@ -541,31 +1511,214 @@ impl<'a> Parser<'a> {
                 return Some(Expr::Literal(literal.clone()));
             } else {
                 self.errors.push(ParserError::InvalidLiteral {
-                    line: self.previous().line().clone(),
+                    line: *self.previous().line(),
                     lexeme: self.previous().lexeme().to_string(),
                 });
                 return None;
             }
         }
 
+        if self.match_token_types(&[TokenType::LEFTBRACKET]) {
+            let mut elements: Vec<Box<Expr>> = Vec::new();
+
+            if !self.check(&TokenType::RIGHTBRACKET) {
+                loop {
+                    elements.push(Box::new(self.expression()?));
+
+                    if !self.match_token_types(&[TokenType::COMMA]) {
+                        break;
+                    }
+                }
+            }
+
+            if self.consume(&TokenType::RIGHTBRACKET).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect ']' after list elements.".to_string(),
+                });
+                return None;
+            }
+
+            return Some(Expr::List(elements));
+        }
+
+        if self.check(&TokenType::LEFTBRACE) && !self.brace_starts_map() {
+            self.advance();
+            return self.set_literal();
+        }
+
+        if self.match_token_types(&[TokenType::LEFTBRACE]) {
+            let mut entries: Vec<(Box<Expr>, Box<Expr>)> = Vec::new();
+
+            if !self.check(&TokenType::RIGHTBRACE) {
+                loop {
+                    let key = self.map_entry_key()?;
+
+                    if self.consume(&TokenType::COLON).is_none() {
+                        self.errors.push(ParserError::ExpectedExpression {
+                            line: *self.peek().line(),
+                            lexeme: "Expect ':' after map key.".to_string(),
+                        });
+                        return None;
+                    }
+                    entries.push((Box::new(key), Box::new(self.expression()?)));
+
+                    if !self.match_token_types(&[TokenType::COMMA]) {
+                        break;
+                    }
+                }
+            }
+
+            if self.consume(&TokenType::RIGHTBRACE).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect '}' after map entries.".to_string(),
+                });
+                return None;
+            }
+
+            return Some(Expr::Map(entries));
+        }
+
         if self.match_token_types(&[TokenType::LEFTPAREN]) {
+            if self.match_token_types(&[TokenType::RIGHTPAREN]) {
+                return Some(Expr::Tuple(Vec::new()));
+            }
+
             let expr = self.expression()?;
 
-            if self.consume(&TokenType::RIGHTPAREN).is_none() {
-                return None;
+            // A comma turns the parentheses into a tuple rather than a grouping
+            if self.match_token_types(&[TokenType::COMMA]) {
+                let mut elements = vec![Box::new(expr)];
+                while !self.check(&TokenType::RIGHTPAREN) && !self.is_at_end() {
+                    elements.push(Box::new(self.expression()?));
+
+                    if !self.match_token_types(&[TokenType::COMMA]) {
+                        break;
+                    }
+                }
+
+                if self.consume(&TokenType::RIGHTPAREN).is_none() {
+                    self.errors.push(ParserError::ExpectedExpression {
+                        line: *self.peek().line(),
+                        lexeme: "Expect ')' after tuple elements.".to_string(),
+                    });
+                    return None;
+                }
+
+                return Some(Expr::Tuple(elements));
             }
 
+            self.consume(&TokenType::RIGHTPAREN)?;
+
             return Some(Expr::Grouping(Box::new(expr)));
         }
 
         self.errors.push(ParserError::ExpectedExpression {
-            line: self.peek().line().clone(),
+            line: *self.peek().line(),
             lexeme: self.peek().lexeme().to_string(),
         });
 
         None
     }
 
+    // Looks past the `{` at the current token: `{}` and `{key: ...}` start a map, anything else a set
+    fn brace_starts_map(&self) -> bool {
+        let first = self.current + 1;
+        let token_type = |index: usize| self.tokens.get(index).map(|token| token.token_type());
+
+        match token_type(first) {
+            Some(TokenType::RIGHTBRACE) => true,
+            Some(TokenType::LEFTBRACKET) => {
+                // A computed key `[expr]` is followed by `:`; a list element is not
+                let mut depth = 0;
+                for index in first.. {
+                    match token_type(index) {
+                        Some(TokenType::LEFTBRACKET) => depth += 1,
+                        Some(TokenType::RIGHTBRACKET) => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return token_type(index + 1) == Some(&TokenType::COLON);
+                            }
+                        }
+                        Some(TokenType::EOF) | None => return false,
+                        _ => {}
+                    }
+                }
+                false
+            }
+            _ => token_type(first + 1) == Some(&TokenType::COLON),
+        }
+    }
+
+    // The elements of `{a, b, ...rest}` after the opening brace
+    fn set_literal(&mut self) -> Option<Expr> {
+        let mut elements: Vec<(Box<Expr>, bool)> = Vec::new();
+
+        loop {
+            let spread = self.match_token_types(&[TokenType::DOTDOTDOT]);
+            elements.push((Box::new(self.expression()?), spread));
+
+            if !self.match_token_types(&[TokenType::COMMA]) || self.check(&TokenType::RIGHTBRACE) {
+                break;
+            }
+        }
+
+        if self.consume(&TokenType::RIGHTBRACE).is_none() {
+            self.errors.push(ParserError::ExpectedExpression {
+                line: *self.peek().line(),
+                lexeme: "Expect '}' after set elements.".to_string(),
+            });
+            return None;
+        }
+
+        Some(Expr::Set(elements))
+    }
+
+    // A key in a map literal: a bare name, a string, a number or a computed `[expr]`
+    fn map_entry_key(&mut self) -> Option<Expr> {
+        if self.match_token_types(&[TokenType::IDENTIFIER, TokenType::STRING]) {
+            let key = self.previous().lexeme().to_string();
+            return Some(Expr::Literal(Literal::StringLiteral(key)));
+        }
+
+        if self.match_token_types(&[TokenType::NUMBER]) {
+            return self.previous().literal().clone().map(Expr::Literal);
+        }
+
+        if self.match_token_types(&[TokenType::LEFTBRACKET]) {
+            let key = self.expression()?;
+
+            if self.consume(&TokenType::RIGHTBRACKET).is_none() {
+                self.errors.push(ParserError::ExpectedExpression {
+                    line: *self.peek().line(),
+                    lexeme: "Expect ']' after computed map key.".to_string(),
+                });
+                return None;
+            }
+            return Some(key);
+        }
+
+        self.errors.push(ParserError::ExpectedExpression {
+            line: *self.peek().line(),
+            lexeme: format!("Expect map key, found '{}'.", self.peek().lexeme()),
+        });
+        None
+    }
+
+    // A key in a map pattern is a bare name or a quoted string
+    fn map_key(&mut self) -> Option<Token> {
+        if self.match_token_types(&[TokenType::IDENTIFIER, TokenType::STRING]) {
+            return Some(self.previous().clone());
+        }
+
+        self.errors.push(ParserError::ExpectedExpression {
+            line: *self.peek().line(),
+            lexeme: format!("Expect map key, found '{}'.", self.peek().lexeme()),
+        });
+        None
+    }
+
     fn consume(&mut self, token_type: &TokenType) -> Option<&Token> {
         if self.check(token_type) {
             return Some(self.advance());
@@ -575,12 +1728,12 @@ impl<'a> Parser<'a> {
 
         if self.is_at_end() {
             self.errors.push(ParserError::UnclosedParen {
-                line: peeked_token.line().clone(),
+                line: *peeked_token.line(),
                 lexeme: String::from("end"),
             });
         } else {
             self.errors.push(ParserError::UnclosedParen {
-                line: peeked_token.line().clone(),
+                line: *peeked_token.line(),
                 lexeme: peeked_token.lexeme().to_string(),
             });
         }
@@ -600,11 +1753,21 @@ impl<'a> Parser<'a> {
                 &TokenType::FUNC
                 | &TokenType::CLASS
                 | &TokenType::VAR
+                | &TokenType::CONST
+                | &TokenType::ENUM
                 | &TokenType::FOR
                 | &TokenType::WHILE
+                | &TokenType::DO
+                | &TokenType::LOOP
                 | &TokenType::IF
                 | &TokenType::PRINT
-                | &TokenType::RETURN => return,
+                | &TokenType::RETURN
+                | &TokenType::YIELD
+                | &TokenType::MATCH
+                | &TokenType::THROW
+                | &TokenType::TRY
+                | &TokenType::IMPORT
+                | &TokenType::EXPORT => return,
                 _ => {
                     self.advance();
                 }
@@ -620,7 +1783,7 @@ impl<'a> Parser<'a> {
             }
         }
 
-        return false;
+        false
     }
 
     fn check(&self, token_type: &TokenType) -> bool {
@@ -631,6 +1794,44 @@ impl<'a> Parser<'a> {
         self.peek().token_type() == token_type
     }
 
+    fn check_next(&self, token_type: &TokenType) -> bool {
+        match self.tokens.get(self.current + 1) {
+            Some(token) => token.token_type() == token_type,
+            None => false,
+        }
+    }
+
+    // An identifier used as a word of the grammar, such as `bhane`
+    fn check_word(&self, word: &str) -> bool {
+        self.check(&TokenType::IDENTIFIER) && self.peek().lexeme() == word
+    }
+
+    fn match_word(&mut self, word: &str) -> bool {
+        if self.check_word(word) {
+            self.advance();
+            return true;
+        }
+
+        false
+    }
+
+    // Whether the token after the current one can start an operand
+    fn next_begins_operand(&self) -> bool {
+        matches!(
+            self.tokens.get(self.current + 1).map(|token| token.token_type()),
+            Some(
+                TokenType::IDENTIFIER
+                    | TokenType::NUMBER
+                    | TokenType::STRING
+                    | TokenType::TRUE
+                    | TokenType::FALSE
+                    | TokenType::NULL
+                    | TokenType::LEFTPAREN
+                    | TokenType::LEFTBRACKET
+            )
+        )
+    }
+
     fn advance(&mut self) -> &Token {
         if !self.is_at_end() {
             self.current += 1;
@@ -0,0 +1,112 @@
+use core::fmt;
+
+use crate::lexer::{Literal, Token};
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    // `_` matches anything without binding it
+    Wildcard,
+    Literal(Literal),
+    // start, end, inclusive
+    Range(f64, f64, bool),
+    Binding(Token),
+    // element patterns, optional `...rest` pattern
+    List(Vec<Pattern>, Option<Box<Pattern>>),
+    Tuple(Vec<Pattern>),
+    // (key, pattern for its value) pairs; `{naam}` is short for `{naam: naam}`
+    Map(Vec<(Token, Pattern)>),
+    // path to the enum (`Color` or `module.Color`), variant name, patterns for its data
+    Variant(Vec<Token>, Token, Option<Vec<Pattern>>),
+}
+
+impl Pattern {
+    // Every name the pattern binds, in source order
+    pub fn bindings(&self) -> Vec<&Token> {
+        match self {
+            Pattern::Binding(name) => vec![name],
+            Pattern::List(elements, rest) => elements
+                .iter()
+                .chain(rest.as_deref())
+                .flat_map(Pattern::bindings)
+                .collect(),
+            Pattern::Tuple(elements) => elements.iter().flat_map(Pattern::bindings).collect(),
+            Pattern::Map(entries) => entries.iter().flat_map(|(_, pattern)| pattern.bindings()).collect(),
+            Pattern::Variant(_, _, fields) => fields.iter().flatten().flat_map(Pattern::bindings).collect(),
+            Pattern::Wildcard | Pattern::Literal(_) | Pattern::Range(_, _, _) => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Wildcard => write!(f, "_"),
+            Pattern::Literal(Literal::StringLiteral(s)) => write!(f, "\"{}\"", s),
+            Pattern::Literal(literal) => write!(f, "{}", literal),
+            Pattern::Range(start, end, inclusive) => {
+                if *inclusive {
+                    write!(f, "{}..={}", start, end)
+                } else {
+                    write!(f, "{}..{}", start, end)
+                }
+            }
+            Pattern::Binding(name) => write!(f, "{}", name.lexeme()),
+            Pattern::List(elements, rest) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                if let Some(rest) = rest {
+                    if !elements.is_empty() {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "...{}", rest)?;
+                }
+                write!(f, "]")
+            }
+            Pattern::Tuple(elements) => {
+                write!(f, "(")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                if elements.len() == 1 {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            Pattern::Map(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, pattern)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", key.lexeme(), pattern)?;
+                }
+                write!(f, "}}")
+            }
+            Pattern::Variant(path, variant, fields) => {
+                for segment in path {
+                    write!(f, "{}.", segment.lexeme())?;
+                }
+                write!(f, "{}", variant.lexeme())?;
+                if let Some(fields) = fields {
+                    write!(f, "(")?;
+                    for (i, field) in fields.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", field)?;
+                    }
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
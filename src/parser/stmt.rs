@@ -2,22 +2,84 @@ use core::fmt;
 
 use crate::lexer::Token;
 
-use super::{expr::Expr, visitor::StmtVisitor};
+use super::{expr::Expr, pattern::Pattern, visitor::StmtVisitor};
 
 
 
+#[derive(Debug, Clone)]
+pub struct Param {
+    pub name: Token,
+    pub default: Option<Expr>,
+    // Collects the remaining positional arguments into a list
+    pub rest: bool,
+}
+
+impl Param {
+    pub fn new(name: Token, default: Option<Expr>, rest: bool) -> Self {
+        Param { name, default, rest }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Expr>,
+    pub body: Box<Stmt>,
+}
+
+impl MatchArm {
+    pub fn new(pattern: Pattern, guard: Option<Expr>, body: Box<Stmt>) -> Self {
+        MatchArm { pattern, guard, body }
+    }
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)?;
+        if let Some(guard) = &self.guard {
+            write!(f, " if {}", guard)?;
+        }
+        write!(f, " => {}", self.body)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Stmt {
     Block(Vec<Box<Stmt>>),
     Expression(Expr),
     Print(Expr),
     Var(Token, Option<Expr>),
+    // A binding that cannot be reassigned, so it always has an initializer
+    Const(Token, Expr),
+    // pattern, value, declared with `const`; e.g. `let [a, b] = pair;`
+    Destructure(Pattern, Expr, bool),
     //condition, then, else 
     If(Expr, Box<Stmt>, Option<Box<Stmt>>),
-    // condition, body
-    While(Expr, Box<Stmt>),
-    Function(Token, Vec<Token>, Vec<Box<Stmt>>),
+    // condition, body, increment run after every iteration (from a desugared `for`)
+    While(Expr, Box<Stmt>, Option<Expr>),
+    // body, condition checked after each run of the body
+    DoWhile(Box<Stmt>, Expr),
+    // Runs its body until a `break`
+    Loop(Box<Stmt>),
+    // loop variable, collection, body; e.g. `for (x in items)`
+    ForIn(Token, Expr, Box<Stmt>),
+    Break(Token),
+    Continue(Token),
+    Function(Token, Vec<Param>, Vec<Box<Stmt>>),
+    // name, (variant name, field names) pairs
+    Enum(Token, Vec<(Token, Vec<Token>)>),
     Return(Token, Option<Expr>),
+    // Pauses the enclosing generator function, handing out the value
+    Yield(Token, Option<Expr>),
+    // keyword, subject, arms
+    Match(Token, Expr, Vec<MatchArm>),
+    Throw(Token, Expr),
+    // body, catch binding, catch body, finally body
+    Try(Vec<Box<Stmt>>, Option<Token>, Option<Vec<Box<Stmt>>>, Option<Vec<Box<Stmt>>>),
+    // keyword, path, alias
+    Import(Token, Token, Token),
+    // A `let`, `const` or `func` declaration visible to importing files
+    Export(Box<Stmt>),
 }
 
 impl Stmt {
@@ -27,10 +89,24 @@ impl Stmt {
             Stmt::Expression(_expr) => visitor.visit_expression_stmt(self),
             Stmt::Print(_expr) => visitor.visit_print_stmt(self),
             Stmt::Var(_token, _expr) => visitor.visit_var_stmt(self),
+            Stmt::Const(_token, _expr) => visitor.visit_const_stmt(self),
+            Stmt::Destructure(_pattern, _expr, _constant) => visitor.visit_destructure_stmt(self),
             Stmt::If(_expr, _stmt_then, _stmt_else) => visitor.visit_if_stmt(self),
-            Stmt::While(_expr, _stmt) => visitor.visit_while_stmt(self),
+            Stmt::While(_expr, _stmt, _increment) => visitor.visit_while_stmt(self),
+            Stmt::DoWhile(_stmt, _expr) => visitor.visit_do_while_stmt(self),
+            Stmt::Loop(_stmt) => visitor.visit_loop_stmt(self),
+            Stmt::ForIn(_name, _iterable, _stmt) => visitor.visit_for_in_stmt(self),
+            Stmt::Break(_keyword) => visitor.visit_break_stmt(self),
+            Stmt::Continue(_keyword) => visitor.visit_continue_stmt(self),
             Stmt::Function(_name, _params, _body) => visitor.visit_function_stmt(self),
+            Stmt::Enum(_name, _variants) => visitor.visit_enum_stmt(self),
             Stmt::Return(_tok, _expr) => visitor.visit_return_stmt(self),
+            Stmt::Yield(_keyword, _expr) => visitor.visit_yield_stmt(self),
+            Stmt::Match(_keyword, _subject, _arms) => visitor.visit_match_stmt(self),
+            Stmt::Throw(_keyword, _value) => visitor.visit_throw_stmt(self),
+            Stmt::Try(_body, _name, _catch, _finally) => visitor.visit_try_stmt(self),
+            Stmt::Import(_keyword, _path, _alias) => visitor.visit_import_stmt(self),
+            Stmt::Export(_declaration) => visitor.visit_export_stmt(self),
 
         }
     }
@@ -49,10 +125,30 @@ impl fmt::Display for Stmt {
             Stmt::Expression(expr) => write!(f, "{}", expr),
             Stmt::Print(expr) => write!(f, "{}", expr),
             Stmt::Var(tok,expr ) => write!(f, "{} {:?}", tok, expr),
+            Stmt::Const(tok, expr) => write!(f, "const {} {}", tok, expr),
+            Stmt::Destructure(pattern, expr, constant) => write!(f, "{} {} = {}", if *constant { "const" } else { "let" }, pattern, expr),
             Stmt::If(condition, then_stmt, else_stmt ) => write!(f, "{} {} {:?}", condition, then_stmt, else_stmt),
-            Stmt::While(condition, body) => write!(f, "{} {}", condition, *body),
+            Stmt::While(condition, body, increment) => write!(f, "{} {} {:?}", condition, *body, increment),
+            Stmt::DoWhile(body, condition) => write!(f, "{} {}", *body, condition),
+            Stmt::Loop(body) => write!(f, "loop {}", *body),
+            Stmt::ForIn(name, iterable, body) => write!(f, "{} in {} {}", name, iterable, *body),
+            Stmt::Break(keyword) | Stmt::Continue(keyword) => write!(f, "{}", keyword),
             Stmt::Function(name, params, body) => write!(f,"{} {:?} {:?}", name, params, body),
+            Stmt::Enum(name, variants) => write!(f, "enum {} {:?}", name, variants),
             Stmt::Return(keyword, initializer) => write!(f, "{} {:?}", keyword, initializer),
+            Stmt::Yield(keyword, value) => write!(f, "{} {:?}", keyword, value),
+            Stmt::Match(keyword, subject, arms) => {
+                write!(f, "{} {} ", keyword, subject)?;
+                for arm in arms {
+                    write!(f, "{} ", arm)?
+                }
+
+                Ok(())
+            },
+            Stmt::Throw(keyword, value) => write!(f, "{} {}", keyword, value),
+            Stmt::Try(body, name, catch_body, finally_body) => write!(f, "{:?} {:?} {:?} {:?}", body, name, catch_body, finally_body),
+            Stmt::Import(keyword, path, alias) => write!(f, "{} {} {}", keyword, path, alias),
+            Stmt::Export(declaration) => write!(f, "export {}", declaration),
         }
     }
 }
\ No newline at end of file
@@ -2,6 +2,8 @@ use super::{expr::Expr, stmt::Stmt};
 
 pub trait ExprVisitor<T> {
     fn visit_assign_expression(&mut self, expr: &Expr) -> T;
+    fn visit_compound_assign_expression(&mut self, expr: &Expr) -> T;
+    fn visit_update_expression(&mut self, expr: &Expr) -> T;
     fn visit_binary_expression(&mut self, expr: &Expr) -> T;
     fn visit_grouping_expression(&mut self, expr: &Expr) -> T;
     fn visit_literal_expr(&mut self, expr: &Expr) -> T;
@@ -9,15 +11,40 @@ pub trait ExprVisitor<T> {
     fn vist_variable_expr(&mut self, expr: &Expr) -> T;
     fn visit_logical_expression(&mut self, expr: &Expr) -> T;
     fn visit_call_expression(&mut self, expr: &Expr) -> T;
+    fn visit_conditional_expression(&mut self, expr: &Expr) -> T;
+    fn visit_list_expression(&mut self, expr: &Expr) -> T;
+    fn visit_tuple_expression(&mut self, expr: &Expr) -> T;
+    fn visit_map_expression(&mut self, expr: &Expr) -> T;
+    fn visit_set_expression(&mut self, expr: &Expr) -> T;
+    fn visit_index_expression(&mut self, expr: &Expr) -> T;
+    fn visit_set_index_expression(&mut self, expr: &Expr) -> T;
+    fn visit_compound_set_index_expression(&mut self, expr: &Expr) -> T;
+    fn visit_update_index_expression(&mut self, expr: &Expr) -> T;
+    fn visit_destructure_expression(&mut self, expr: &Expr) -> T;
+    fn visit_get_expression(&mut self, expr: &Expr) -> T;
 }
 
 pub trait StmtVisitor<T> {
     fn visit_expression_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_print_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_var_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_const_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_destructure_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_block_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_if_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_while_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_do_while_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_loop_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_for_in_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_break_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_continue_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_function_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_enum_stmt(&mut self, stmt: &Stmt) -> T;
     fn visit_return_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_yield_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_match_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_throw_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_try_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_import_stmt(&mut self, stmt: &Stmt) -> T;
+    fn visit_export_stmt(&mut self, stmt: &Stmt) -> T;
 }
\ No newline at end of file
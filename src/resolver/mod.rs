@@ -0,0 +1,4 @@
+pub mod resolver;
+pub mod resolver_error;
+
+pub use resolver::Resolver;
@@ -0,0 +1,601 @@
+use std::collections::HashMap;
+
+use crate::{
+    lexer::Token,
+    parser::{
+        expr::Expr,
+        pattern::Pattern,
+        stmt::{MatchArm, Stmt},
+        visitor::{ExprVisitor, StmtVisitor},
+    },
+};
+
+use super::resolver_error::ResolverError;
+
+// Walks the parsed program before it runs and reports mistakes that can be found statically
+pub struct Resolver {
+    // Innermost scope last; each maps a declared name to whether it is a constant
+    scopes: Vec<HashMap<String, bool>>,
+    // Variant names of the enums declared in the matching scope of `scopes`
+    enums: Vec<HashMap<String, Vec<String>>>,
+    // How many loops enclose the current statement within the current function
+    loop_depth: usize,
+    // How many functions enclose the current statement
+    function_depth: usize,
+    // How many `try` statements enclose the current statement within the current function
+    try_depth: usize,
+    errors: Vec<ResolverError>,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Resolver {
+            scopes: vec![HashMap::new()],
+            enums: vec![HashMap::new()],
+            loop_depth: 0,
+            function_depth: 0,
+            try_depth: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn get_errors(&self) -> &Vec<ResolverError> {
+        &self.errors
+    }
+
+    pub fn resolve(&mut self, statements: &[Stmt]) {
+        for stmt in statements {
+            self.resolve_stmt(stmt);
+        }
+    }
+
+    fn resolve_stmt(&mut self, stmt: &Stmt) {
+        stmt.accept(self);
+    }
+
+    fn resolve_block(&mut self, statements: &[Box<Stmt>]) {
+        self.begin_scope();
+        for stmt in statements {
+            self.resolve_stmt(stmt);
+        }
+        self.end_scope();
+    }
+
+    fn resolve_expr(&mut self, expr: &Expr) {
+        expr.accept(self);
+    }
+
+    fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+        self.enums.push(HashMap::new());
+    }
+
+    fn end_scope(&mut self) {
+        self.scopes.pop();
+        self.enums.pop();
+    }
+
+    fn resolve_loop_body(&mut self, body: &Stmt) {
+        self.loop_depth += 1;
+        self.resolve_stmt(body);
+        self.loop_depth -= 1;
+    }
+
+    fn check_inside_loop(&mut self, keyword: &Token) {
+        if self.loop_depth == 0 {
+            self.errors.push(ResolverError::OutsideLoop {
+                line: *keyword.line(),
+                lexeme: keyword.lexeme().to_string(),
+            });
+        }
+    }
+
+    fn declare(&mut self, name: &Token, constant: bool) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.lexeme().to_string(), constant);
+        }
+        // A later declaration of the same name hides any enum declared here
+        if let Some(enums) = self.enums.last_mut() {
+            enums.remove(name.lexeme());
+        }
+    }
+
+    // The variants of the enum `name` refers to, if the nearest declaration of it is an enum
+    fn lookup_enum(&self, name: &str) -> Option<&Vec<String>> {
+        self.scopes
+            .iter()
+            .zip(self.enums.iter())
+            .rev()
+            .find(|(scope, _)| scope.contains_key(name))
+            .and_then(|(_, enums)| enums.get(name))
+    }
+
+    // The name and variants of the enum a variant pattern refers to, when it is known before running
+    fn pattern_enum(&self, pattern: &Pattern) -> Option<(String, Vec<String>)> {
+        match pattern {
+            Pattern::Variant(path, _, _) if path.len() == 1 => {
+                let name = path[0].lexeme();
+                self.lookup_enum(name).map(|variants| (name.to_string(), variants.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    // Reports variant patterns naming a variant their enum does not have
+    fn check_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Variant(_, name, fields) => {
+                if let Some((enum_name, variants)) = self.pattern_enum(pattern) {
+                    if !variants.iter().any(|variant| variant == name.lexeme()) {
+                        self.errors.push(ResolverError::UnknownVariant {
+                            line: *name.line(),
+                            lexeme: format!("{}.{}", enum_name, name.lexeme()),
+                        });
+                    }
+                }
+                for field in fields.iter().flatten() {
+                    self.check_pattern(field);
+                }
+            }
+            Pattern::List(elements, rest) => {
+                for element in elements.iter().chain(rest.as_deref()) {
+                    self.check_pattern(element);
+                }
+            }
+            Pattern::Tuple(elements) => {
+                for element in elements {
+                    self.check_pattern(element);
+                }
+            }
+            Pattern::Map(entries) => {
+                for (_, pattern) in entries {
+                    self.check_pattern(pattern);
+                }
+            }
+            Pattern::Wildcard | Pattern::Literal(_) | Pattern::Range(_, _, _) | Pattern::Binding(_) => {}
+        }
+    }
+
+    // A match whose arms are all variants of one known enum must name every variant or have a catch-all arm
+    fn check_exhaustive(&mut self, keyword: &Token, arms: &[MatchArm]) {
+        let catches_all = |pattern: &Pattern| matches!(pattern, Pattern::Wildcard | Pattern::Binding(_));
+
+        let Some((enum_name, variants)) = arms.first().and_then(|arm| self.pattern_enum(&arm.pattern)) else {
+            return;
+        };
+
+        let mut covered: Vec<&str> = Vec::new();
+        for arm in arms {
+            if arm.guard.is_none() && catches_all(&arm.pattern) {
+                return;
+            }
+
+            match &arm.pattern {
+                Pattern::Variant(path, name, fields) if path.len() == 1 && path[0].lexeme() == enum_name => {
+                    // Only an unguarded arm whose fields match anything covers the whole variant
+                    if arm.guard.is_none() && fields.iter().flatten().all(catches_all) {
+                        covered.push(name.lexeme());
+                    }
+                }
+                _ => return,
+            }
+        }
+
+        let missing: Vec<String> = variants
+            .iter()
+            .filter(|variant| !covered.contains(&variant.as_str()))
+            .map(|variant| format!("{}.{}", enum_name, variant))
+            .collect();
+
+        if !missing.is_empty() {
+            self.errors.push(ResolverError::NonExhaustiveMatch {
+                line: *keyword.line(),
+                lexeme: keyword.lexeme().to_string(),
+                missing,
+            });
+        }
+    }
+
+    fn declare_pattern(&mut self, pattern: &Pattern, constant: bool) {
+        for name in pattern.bindings() {
+            self.declare(name, constant);
+        }
+    }
+
+    // Names that are not declared yet may still exist at runtime, so only known constants are reported
+    fn check_assignment(&mut self, name: &Token) {
+        let constant = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name.lexeme()))
+            .copied()
+            .unwrap_or(false);
+
+        if constant {
+            self.errors.push(ResolverError::ConstantReassignment {
+                line: *name.line(),
+                lexeme: name.lexeme().to_string(),
+            });
+        }
+    }
+}
+
+impl ExprVisitor<()> for Resolver {
+    fn visit_assign_expression(&mut self, expr: &Expr) {
+        if let Expr::Assign(name, value) = expr {
+            self.resolve_expr(value);
+            self.check_assignment(name);
+        }
+    }
+
+    fn visit_compound_assign_expression(&mut self, expr: &Expr) {
+        if let Expr::CompoundAssign(name, _operator, value) = expr {
+            self.resolve_expr(value);
+            self.check_assignment(name);
+        }
+    }
+
+    fn visit_update_expression(&mut self, expr: &Expr) {
+        if let Expr::Update(name, _operator, _prefix) = expr {
+            self.check_assignment(name);
+        }
+    }
+
+    fn visit_binary_expression(&mut self, expr: &Expr) {
+        if let Expr::Binary(left, _operator, right) = expr {
+            self.resolve_expr(left);
+            self.resolve_expr(right);
+        }
+    }
+
+    fn visit_grouping_expression(&mut self, expr: &Expr) {
+        if let Expr::Grouping(inner) = expr {
+            self.resolve_expr(inner);
+        }
+    }
+
+    fn visit_literal_expr(&mut self, _expr: &Expr) {}
+
+    fn visit_unary_expr(&mut self, expr: &Expr) {
+        if let Expr::Unary(_operator, operand) = expr {
+            self.resolve_expr(operand);
+        }
+    }
+
+    fn vist_variable_expr(&mut self, _expr: &Expr) {}
+
+    fn visit_logical_expression(&mut self, expr: &Expr) {
+        if let Expr::Logical(left, _operator, right) = expr {
+            self.resolve_expr(left);
+            self.resolve_expr(right);
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &Expr) {
+        if let Expr::Call(callee, _paren, args, named, _optional) = expr {
+            self.resolve_expr(callee);
+            for argument in args {
+                self.resolve_expr(argument);
+            }
+            for (_name, argument) in named {
+                self.resolve_expr(argument);
+            }
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &Expr) {
+        if let Expr::Conditional(condition, then_expr, else_expr) = expr {
+            self.resolve_expr(condition);
+            self.resolve_expr(then_expr);
+            self.resolve_expr(else_expr);
+        }
+    }
+
+    fn visit_list_expression(&mut self, expr: &Expr) {
+        if let Expr::List(elements) = expr {
+            for element in elements {
+                self.resolve_expr(element);
+            }
+        }
+    }
+
+    fn visit_tuple_expression(&mut self, expr: &Expr) {
+        if let Expr::Tuple(elements) = expr {
+            for element in elements {
+                self.resolve_expr(element);
+            }
+        }
+    }
+
+    fn visit_map_expression(&mut self, expr: &Expr) {
+        if let Expr::Map(entries) = expr {
+            for (key, value) in entries {
+                self.resolve_expr(key);
+                self.resolve_expr(value);
+            }
+        }
+    }
+
+    fn visit_set_expression(&mut self, expr: &Expr) {
+        if let Expr::Set(elements) = expr {
+            for (element, _spread) in elements {
+                self.resolve_expr(element);
+            }
+        }
+    }
+
+    fn visit_index_expression(&mut self, expr: &Expr) {
+        if let Expr::Index(object, _bracket, index) = expr {
+            self.resolve_expr(object);
+            self.resolve_expr(index);
+        }
+    }
+
+    fn visit_set_index_expression(&mut self, expr: &Expr) {
+        if let Expr::SetIndex(object, _bracket, index, value) = expr {
+            self.resolve_expr(object);
+            self.resolve_expr(index);
+            self.resolve_expr(value);
+        }
+    }
+
+    fn visit_compound_set_index_expression(&mut self, expr: &Expr) {
+        if let Expr::CompoundSetIndex(object, _bracket, index, _operator, value) = expr {
+            self.resolve_expr(object);
+            self.resolve_expr(index);
+            self.resolve_expr(value);
+        }
+    }
+
+    fn visit_update_index_expression(&mut self, expr: &Expr) {
+        if let Expr::UpdateIndex(object, _bracket, index, _operator, _prefix) = expr {
+            self.resolve_expr(object);
+            self.resolve_expr(index);
+        }
+    }
+
+    fn visit_destructure_expression(&mut self, expr: &Expr) {
+        if let Expr::Destructure(pattern, _equals, value) = expr {
+            self.resolve_expr(value);
+            for name in pattern.bindings() {
+                self.check_assignment(name);
+            }
+        }
+    }
+
+    fn visit_get_expression(&mut self, expr: &Expr) {
+        if let Expr::Get(object, _name, _optional) = expr {
+            self.resolve_expr(object);
+        }
+    }
+}
+
+impl StmtVisitor<()> for Resolver {
+    fn visit_expression_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Expression(expr) = stmt {
+            self.resolve_expr(expr);
+        }
+    }
+
+    fn visit_print_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Print(expr) = stmt {
+            self.resolve_expr(expr);
+        }
+    }
+
+    fn visit_var_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Var(name, initializer) = stmt {
+            if let Some(initializer) = initializer {
+                self.resolve_expr(initializer);
+            }
+            self.declare(name, false);
+        }
+    }
+
+    fn visit_const_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Const(name, initializer) = stmt {
+            self.resolve_expr(initializer);
+            self.declare(name, true);
+        }
+    }
+
+    fn visit_destructure_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Destructure(pattern, initializer, constant) = stmt {
+            self.resolve_expr(initializer);
+            self.declare_pattern(pattern, *constant);
+        }
+    }
+
+    fn visit_block_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Block(statements) = stmt {
+            self.resolve_block(statements);
+        }
+    }
+
+    fn visit_if_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::If(condition, then_branch, else_branch) = stmt {
+            self.resolve_expr(condition);
+            self.resolve_stmt(then_branch);
+            if let Some(else_branch) = else_branch {
+                self.resolve_stmt(else_branch);
+            }
+        }
+    }
+
+    fn visit_while_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::While(condition, body, increment) = stmt {
+            self.resolve_expr(condition);
+            self.resolve_loop_body(body);
+            if let Some(increment) = increment {
+                self.resolve_expr(increment);
+            }
+        }
+    }
+
+    fn visit_do_while_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::DoWhile(body, condition) = stmt {
+            self.resolve_loop_body(body);
+            self.resolve_expr(condition);
+        }
+    }
+
+    fn visit_loop_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Loop(body) = stmt {
+            self.resolve_loop_body(body);
+        }
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::ForIn(name, iterable, body) = stmt {
+            self.resolve_expr(iterable);
+
+            self.begin_scope();
+            self.declare(name, false);
+            self.resolve_loop_body(body);
+            self.end_scope();
+        }
+    }
+
+    fn visit_break_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Break(keyword) = stmt {
+            self.check_inside_loop(keyword);
+        }
+    }
+
+    fn visit_continue_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Continue(keyword) = stmt {
+            self.check_inside_loop(keyword);
+        }
+    }
+
+    fn visit_function_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Function(name, params, body) = stmt {
+            self.declare(name, false);
+
+            // A loop around the declaration does not make `break` valid inside the body
+            let enclosing_loop_depth = std::mem::replace(&mut self.loop_depth, 0);
+            let enclosing_try_depth = std::mem::replace(&mut self.try_depth, 0);
+            self.function_depth += 1;
+
+            // Parameters and the body share one scope, as they do at runtime
+            self.begin_scope();
+            for param in params {
+                if let Some(default) = &param.default {
+                    self.resolve_expr(default);
+                }
+                self.declare(&param.name, false);
+            }
+            for stmt in body {
+                self.resolve_stmt(stmt);
+            }
+            self.end_scope();
+
+            self.loop_depth = enclosing_loop_depth;
+            self.try_depth = enclosing_try_depth;
+            self.function_depth -= 1;
+        }
+    }
+
+    fn visit_enum_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Enum(name, variants) = stmt {
+            self.declare(name, false);
+
+            let variants = variants.iter().map(|(variant, _)| variant.lexeme().to_string()).collect();
+            if let Some(enums) = self.enums.last_mut() {
+                enums.insert(name.lexeme().to_string(), variants);
+            }
+        }
+    }
+
+    fn visit_return_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Return(_keyword, Some(value)) = stmt {
+            self.resolve_expr(value);
+        }
+    }
+
+    fn visit_yield_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Yield(keyword, value) = stmt {
+            if let Some(value) = value {
+                self.resolve_expr(value);
+            }
+
+            let line = *keyword.line();
+            let lexeme = keyword.lexeme().to_string();
+            if self.function_depth == 0 {
+                self.errors.push(ResolverError::YieldOutsideFunction { line, lexeme });
+            } else if self.try_depth > 0 {
+                // A paused generator cannot keep a `try` open
+                self.errors.push(ResolverError::YieldInsideTry { line, lexeme });
+            }
+        }
+    }
+
+    fn visit_match_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Match(keyword, subject, arms) = stmt {
+            self.resolve_expr(subject);
+
+            for arm in arms {
+                self.check_pattern(&arm.pattern);
+            }
+            self.check_exhaustive(keyword, arms);
+
+            for arm in arms {
+                self.begin_scope();
+                self.declare_pattern(&arm.pattern, false);
+                if let Some(guard) = &arm.guard {
+                    self.resolve_expr(guard);
+                }
+                self.resolve_stmt(&arm.body);
+                self.end_scope();
+            }
+        }
+    }
+
+    fn visit_throw_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Throw(_keyword, value) = stmt {
+            self.resolve_expr(value);
+        }
+    }
+
+    fn visit_try_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Try(body, catch_name, catch_body, finally_body) = stmt {
+            self.try_depth += 1;
+            self.resolve_block(body);
+
+            if let Some(catch_body) = catch_body {
+                self.begin_scope();
+                if let Some(name) = catch_name {
+                    self.declare(name, false);
+                }
+                for stmt in catch_body {
+                    self.resolve_stmt(stmt);
+                }
+                self.end_scope();
+            }
+
+            if let Some(finally_body) = finally_body {
+                self.resolve_block(finally_body);
+            }
+            self.try_depth -= 1;
+        }
+    }
+
+    fn visit_import_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Import(_keyword, _path, alias) = stmt {
+            self.declare(alias, false);
+        }
+    }
+
+    fn visit_export_stmt(&mut self, stmt: &Stmt) {
+        if let Stmt::Export(declaration) = stmt {
+            self.resolve_stmt(declaration);
+        }
+    }
+}
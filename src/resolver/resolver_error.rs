@@ -0,0 +1,55 @@
+use core::fmt;
+
+#[derive(Debug)]
+pub enum ResolverError {
+    ConstantReassignment {
+        line: usize,
+        lexeme: String,
+    },
+    OutsideLoop {
+        line: usize,
+        lexeme: String,
+    },
+    YieldOutsideFunction {
+        line: usize,
+        lexeme: String,
+    },
+    YieldInsideTry {
+        line: usize,
+        lexeme: String,
+    },
+    UnknownVariant {
+        line: usize,
+        lexeme: String,
+    },
+    NonExhaustiveMatch {
+        line: usize,
+        lexeme: String,
+        missing: Vec<String>,
+    },
+}
+
+impl fmt::Display for ResolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolverError::ConstantReassignment { line, lexeme } => {
+                write!(f, "Line {} at '{}': Cannot assign to a constant.", line, lexeme)
+            },
+            ResolverError::OutsideLoop { line, lexeme } => {
+                write!(f, "Line {} at '{}': Can only be used inside a loop.", line, lexeme)
+            },
+            ResolverError::YieldOutsideFunction { line, lexeme } => {
+                write!(f, "Line {} at '{}': Can only yield inside a function.", line, lexeme)
+            },
+            ResolverError::YieldInsideTry { line, lexeme } => {
+                write!(f, "Line {} at '{}': Cannot yield inside a try statement.", line, lexeme)
+            },
+            ResolverError::UnknownVariant { line, lexeme } => {
+                write!(f, "Line {} at '{}': No such enum variant.", line, lexeme)
+            },
+            ResolverError::NonExhaustiveMatch { line, lexeme, missing } => {
+                write!(f, "Line {} at '{}': Match does not cover {}.", line, lexeme, missing.join(", "))
+            },
+        }
+    }
+}
@@ -1,4 +1,42 @@
-use neplat::{lexer::{Literal, Token, TokenType}, parser::stmt::Stmt, Interpreter, Parser};
+use std::{cell::RefCell, fs, io::Cursor, path::PathBuf, rc::Rc};
+
+use neplat::{interpreter::{files::FileAccess, interpret_error::InterpretError, json, native::Arity}, lexer::{Literal, Token, TokenType}, parser::stmt::Stmt, Interpreter, Lexer, Parser};
+
+fn run(source: &str) -> Interpreter {
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    assert!(parser.get_errors().is_empty(), "{:?}", parser.get_errors());
+
+    let mut interpreter = Interpreter::new();
+    let _ = interpreter.interpret(&ast);
+    interpreter
+}
+
+// Writes `files` into a fresh directory and runs `main.neplat` from it
+fn run_files(test_name: &str, files: &[(&str, &str)]) -> Interpreter {
+    let dir: PathBuf = std::env::temp_dir().join(format!("neplat_{}_{}", test_name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for (name, source) in files {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, source).unwrap();
+    }
+
+    let main_path = dir.join("main.neplat");
+    let source = fs::read_to_string(&main_path).unwrap();
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    assert!(parser.get_errors().is_empty(), "{:?}", parser.get_errors());
+
+    let mut interpreter = Interpreter::new();
+    interpreter.set_script_path(&main_path);
+    let _ = interpreter.interpret(&ast);
+
+    let _ = fs::remove_dir_all(&dir);
+    interpreter
+}
 
 #[test]
 fn test_interpreter_simple_addition() {
@@ -70,3 +108,1243 @@ fn test_interpreter_variable_declaration() {
     let value = interpreter.get_variable("x");
     assert!(matches!(value, Some(Literal::NumberLiteral(5.0))));
 }
+
+#[test]
+fn test_interpreter_default_and_named_arguments() {
+    let interpreter = run(r#"
+        let result;
+        func greet(naam, abhivadan = "Namaste", chinha = "!") {
+            result = abhivadan + " " + naam + chinha;
+        }
+        greet("Ram");
+        let first = result;
+        greet(chinha: "?", naam: "Sita");
+        let second = result;
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(matches!(interpreter.get_variable("first"), Some(Literal::StringLiteral(s)) if s == "Namaste Ram!"));
+    assert!(matches!(interpreter.get_variable("second"), Some(Literal::StringLiteral(s)) if s == "Namaste Sita?"));
+}
+
+#[test]
+fn test_interpreter_rest_parameter_collects_extra_arguments() {
+    let interpreter = run(r#"
+        let rest;
+        func collect(first, ...others) {
+            rest = others;
+        }
+        collect(1, 2, 3);
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    match interpreter.get_variable("rest") {
+        Some(Literal::List(items)) => assert_eq!(items.borrow().len(), 2),
+        other => panic!("Expected a list, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpreter_arity_errors() {
+    let interpreter = run(r#"
+        func pair(a, b = 2) {}
+        pair();
+        pair(1, 2, 3);
+        pair(1, c: 3);
+        pair(1, a: 3);
+    "#);
+
+    assert_eq!(interpreter.get_errors().len(), 4);
+}
+
+#[test]
+fn test_interpreter_compound_assignment_and_increment() {
+    let interpreter = run(r#"
+        manum counter bhaneko 10;
+        counter joda_bhaneko 5;
+        counter %= 4;
+        let before = counter++;
+        let after = ++counter;
+        counter ek_ghatau;
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(matches!(interpreter.get_variable("before"), Some(Literal::NumberLiteral(3.0))));
+    assert!(matches!(interpreter.get_variable("after"), Some(Literal::NumberLiteral(5.0))));
+    assert!(matches!(interpreter.get_variable("counter"), Some(Literal::NumberLiteral(4.0))));
+}
+
+#[test]
+fn test_interpreter_double_minus_before_an_operand_subtracts() {
+    let interpreter = run(r#"
+        let a = 5;
+        let b = 2;
+        let spaced = a - -b;
+        let joined = a--b;
+        let scaled = a--b * 3;
+        let stepped = a-- - b;
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("spaced"), Some(Literal::NumberLiteral(7.0))));
+    assert!(matches!(interpreter.get_variable("joined"), Some(Literal::NumberLiteral(7.0))));
+    assert!(matches!(interpreter.get_variable("scaled"), Some(Literal::NumberLiteral(11.0))));
+    assert!(matches!(interpreter.get_variable("stepped"), Some(Literal::NumberLiteral(3.0))));
+    assert!(matches!(interpreter.get_variable("a"), Some(Literal::NumberLiteral(4.0))));
+}
+
+#[test]
+fn test_interpreter_compound_assignment_on_items_and_keys() {
+    let interpreter = run(r#"
+        let calls = 0;
+        func pick() { calls++; return 1; }
+
+        let xs = [1, 2, 3];
+        xs[0] += 5;
+        xs[pick()] *= 10;
+        let old = xs[-1]++;
+        let new = --xs[pick()];
+
+        let scores = {ram: 1};
+        scores.ram joda_bhaneko 2;
+        scores.sita = 7;
+        scores["sita"]++;
+        let total = ++scores.ram + scores.sita;
+
+        let messages = "";
+        try { let pair = (1, 2); pair[0] += 1; } catch (e) { messages = messages + e.message + "; "; }
+        try { scores.hari++; } catch (e) { messages = messages + e.message; }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("xs").unwrap().to_string(), "[6, 19, 4]");
+    assert!(matches!(interpreter.get_variable("calls"), Some(Literal::NumberLiteral(2.0))));
+    assert!(matches!(interpreter.get_variable("old"), Some(Literal::NumberLiteral(3.0))));
+    assert!(matches!(interpreter.get_variable("new"), Some(Literal::NumberLiteral(19.0))));
+    assert_eq!(interpreter.get_variable("scores").unwrap().to_string(), "{ram: 4, sita: 8}");
+    assert!(matches!(interpreter.get_variable("total"), Some(Literal::NumberLiteral(12.0))));
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "Tuples are immutable; build a new tuple instead; Map has no key hari"
+    );
+}
+
+#[test]
+fn test_interpreter_conditional_only_evaluates_taken_branch() {
+    let interpreter = run(r#"
+        let unset;
+        let x = 5;
+        let size = x > 3 ? "thulo" : unset;
+        manum other bhaneko yadi x bhanda_sano 3 bhane unset natra "sano";
+        let bhane = 2;
+        let picked;
+        func choose(value) { picked = value; }
+        yadi (bhane > 1) bhane choose("dui") natra choose("ek");
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(matches!(interpreter.get_variable("size"), Some(Literal::StringLiteral(s)) if s == "thulo"));
+    assert!(matches!(interpreter.get_variable("other"), Some(Literal::StringLiteral(s)) if s == "sano"));
+    assert!(matches!(interpreter.get_variable("picked"), Some(Literal::StringLiteral(s)) if s == "dui"));
+}
+
+#[test]
+fn test_interpreter_if_and_conditional_agree_on_truthiness() {
+    let interpreter = run(r#"
+        let from_if = "";
+        let from_conditional = "";
+        for (value in [1, 0, "", [], galat]) {
+            let taken = "natra";
+            if (value) { taken = "yadi"; }
+            from_if = from_if + taken + " ";
+            from_conditional = from_conditional + (value ? "yadi" : "natra") + " ";
+        }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("from_if").unwrap().to_string(), "yadi yadi yadi yadi natra ");
+    assert_eq!(interpreter.get_variable("from_conditional").unwrap().to_string(), "yadi yadi yadi yadi natra ");
+}
+
+#[test]
+fn test_interpreter_null_coalescing_and_optional_call() {
+    let interpreter = run(r#"
+        let unset;
+        let missing_fn;
+        let fallback = unset ?? "default";
+        let kept = 0 khali_bhaye 9;
+        let skipped = missing_fn?.(undefined_argument) ?? "skipped";
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(matches!(interpreter.get_variable("fallback"), Some(Literal::StringLiteral(s)) if s == "default"));
+    assert!(matches!(interpreter.get_variable("kept"), Some(Literal::NumberLiteral(0.0))));
+    assert!(matches!(interpreter.get_variable("skipped"), Some(Literal::StringLiteral(s)) if s == "skipped"));
+}
+
+#[test]
+fn test_interpreter_match_binds_in_arm_scope() {
+    let interpreter = run(r#"
+        let result;
+        match ([1, 2, 3]) {
+            [] => result = "empty";
+            [first, ...rest] yadi first > 5 => result = "big";
+            [first, ...rest] => result = rest;
+        }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(interpreter.get_variable("first").is_none());
+    match interpreter.get_variable("result") {
+        Some(Literal::List(items)) => assert_eq!(items.borrow().len(), 2),
+        other => panic!("Expected a list, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpreter_non_exhaustive_match() {
+    let interpreter = run(r#"
+        match ("kalo") {
+            "rato" => print "red";
+            1..5 => print "number";
+        }
+    "#);
+
+    assert!(matches!(interpreter.get_errors()[0], InterpretError::NonExhaustiveMatch(_)));
+}
+
+#[test]
+fn test_interpreter_catches_builtin_errors_as_values() {
+    let interpreter = run(r#"
+        let kind;
+        let line;
+        let cleaned = false;
+        func divide(a, b) {
+            return a / b;
+        }
+        prayas {
+            divide(1, 0);
+        } samatnus (e) {
+            kind = e.kind;
+            line = e.line;
+        } antama {
+            cleaned = true;
+        }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(matches!(interpreter.get_variable("kind"), Some(Literal::StringLiteral(s)) if s == "DivisionByZero"));
+    assert!(matches!(interpreter.get_variable("line"), Some(Literal::NumberLiteral(6.0))));
+    assert!(matches!(interpreter.get_variable("cleaned"), Some(Literal::BooleanLiteral(true))));
+}
+
+#[test]
+fn test_interpreter_finally_runs_while_unwinding() {
+    let interpreter = run(r#"
+        let steps = 0;
+        func early() {
+            try {
+                return "done";
+            } finally {
+                steps += 1;
+            }
+        }
+        let result = early();
+        try {
+            try {
+                throw "andar";
+            } finally {
+                steps += 1;
+            }
+        } catch (e) {
+            steps += 1;
+        }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty());
+    assert!(matches!(interpreter.get_variable("result"), Some(Literal::StringLiteral(s)) if s == "done"));
+    assert!(matches!(interpreter.get_variable("steps"), Some(Literal::NumberLiteral(3.0))));
+}
+
+#[test]
+fn test_interpreter_uncaught_error_keeps_stack_trace() {
+    let interpreter = run(r#"
+        func inner() {
+            throw "boom";
+        }
+        func outer() {
+            inner();
+        }
+        outer();
+    "#);
+
+    assert!(matches!(interpreter.get_errors()[0], InterpretError::Thrown(_)));
+    let trace = interpreter.get_uncaught_errors()[0].stack_trace();
+    assert!(trace.contains("in inner called at line 6"));
+    assert!(trace.contains("in outer called at line 8"));
+}
+
+
+#[test]
+fn test_interpreter_import_exposes_exports() {
+    let interpreter = run_files("import_exports", &[
+        ("main.neplat", r#"
+            import "lib/counter.neplat" as counter;
+            ayat "lib/counter" bhanera again;
+            counter.bump();
+            again.bump();
+            let total = counter.count;
+            let same = counter.twice(4);
+        "#),
+        ("lib/counter.neplat", r#"
+            import "helpers.neplat" as helpers;
+            export let count = 0;
+            let hidden = 1;
+            export func bump() {
+                count += hidden;
+            }
+            niryat karya twice(n) {
+                dinus helpers.double(n);
+            }
+        "#),
+        ("lib/helpers.neplat", r#"
+            export func double(n) {
+                return n * 2;
+            }
+        "#),
+    ]);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    // Both imports share one cached module, so both bumps hit the same counter
+    assert!(matches!(interpreter.get_variable("total"), Some(Literal::NumberLiteral(2.0))));
+    assert!(matches!(interpreter.get_variable("same"), Some(Literal::NumberLiteral(8.0))));
+}
+
+#[test]
+fn test_interpreter_import_errors() {
+    let interpreter = run_files("import_errors", &[
+        ("main.neplat", r#"
+            import "a.neplat" as a;
+            import "missing.neplat" as missing;
+            import "b.neplat" as b;
+            let secret = b.hidden;
+        "#),
+        ("a.neplat", r#"
+            import "main.neplat" as main;
+        "#),
+        ("b.neplat", r#"
+            let hidden = 1;
+        "#),
+    ]);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], InterpretError::ImportError(message) if message.starts_with("Circular import")));
+    assert!(matches!(&errors[1], InterpretError::ImportError(message) if message.contains("missing.neplat")));
+    assert!(matches!(&errors[2], InterpretError::UndefinedProperty(_)));
+}
+
+#[test]
+fn test_interpreter_imports_stay_inside_the_file_root() {
+    let dir = std::env::temp_dir().join(format!("neplat_import_root_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("root")).unwrap();
+    fs::write(dir.join("root/inside.neplat"), "export let value = 1;").unwrap();
+    fs::write(dir.join("outside.neplat"), "export let value = 2;").unwrap();
+    let main_path = dir.join("root/main.neplat");
+    fs::write(&main_path, r#"
+        import "inside" as inside;
+        import "../outside" as outside;
+    "#).unwrap();
+
+    let source = fs::read_to_string(&main_path).unwrap();
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let ast = Parser::new(&tokens).parse();
+    let mut interpreter = Interpreter::new();
+    interpreter.set_script_path(&main_path);
+    interpreter.set_file_access(FileAccess::new(&dir.join("root")).unwrap());
+    let _ = interpreter.interpret(&ast);
+    let _ = fs::remove_dir_all(&dir);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::ImportError(message) if message == "Cannot import '../outside'; it is outside the allowed directory"));
+    assert!(interpreter.get_variable("outside").is_none());
+}
+
+#[test]
+fn test_interpreter_rejects_constant_reassignment_at_runtime() {
+    // The resolver cannot see this one: `reset` is declared before `limit` exists
+    let interpreter = run(r#"
+        func reset() {
+            limit = 0;
+        }
+        const limit = 10;
+        reset();
+        let after = limit;
+    "#);
+
+    assert!(matches!(&interpreter.get_errors()[0], InterpretError::ConstantReassignment(message) if message.contains("limit")));
+    assert!(matches!(interpreter.get_variable("after"), Some(Literal::NumberLiteral(10.0))));
+}
+
+#[test]
+fn test_interpreter_loop_forms_with_break_and_continue() {
+    let interpreter = run(r#"
+        let runs = 0;
+        do {
+            runs++;
+        } while (false);
+
+        let n = 0;
+        dohoryau {
+            n++;
+            yadi (n == 5) { roka; }
+        }
+
+        let odd_sum = 0;
+        for (let i = 0; i < 6; i++) {
+            if (i % 2 == 0) { jari; }
+            odd_sum += i;
+        }
+
+        let tries = 0;
+        gara {
+            tries++;
+            if (tries < 3) { continue; }
+        } jaba_samma (tries < 10 and tries != 3);
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("runs"), Some(Literal::NumberLiteral(1.0))));
+    assert!(matches!(interpreter.get_variable("n"), Some(Literal::NumberLiteral(5.0))));
+    assert!(matches!(interpreter.get_variable("odd_sum"), Some(Literal::NumberLiteral(9.0))));
+    assert!(matches!(interpreter.get_variable("tries"), Some(Literal::NumberLiteral(3.0))));
+}
+
+#[test]
+fn test_interpreter_destructuring_lists_and_maps() {
+    let interpreter = run(r#"
+        let pair = [1, 2];
+        let [a, b] = pair;
+        [a, b] = [b, a];
+
+        let person = {naam: "Sita", umer: 30, "thegana": {sahar: "Pokhara"}};
+        manum {naam, umer: age, thegana: {sahar}} bhaneko person;
+
+        let [first, ...rest] = [10, 20, 30];
+        let [_, second] = [5, 6];
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("a"), Some(Literal::NumberLiteral(2.0))));
+    assert!(matches!(interpreter.get_variable("b"), Some(Literal::NumberLiteral(1.0))));
+    assert!(matches!(interpreter.get_variable("naam"), Some(Literal::StringLiteral(s)) if s == "Sita"));
+    assert!(matches!(interpreter.get_variable("age"), Some(Literal::NumberLiteral(30.0))));
+    assert!(matches!(interpreter.get_variable("sahar"), Some(Literal::StringLiteral(s)) if s == "Pokhara"));
+    assert!(matches!(interpreter.get_variable("first"), Some(Literal::NumberLiteral(10.0))));
+    assert!(matches!(interpreter.get_variable("rest"), Some(Literal::List(items)) if items.borrow().len() == 2));
+    assert!(matches!(interpreter.get_variable("second"), Some(Literal::NumberLiteral(6.0))));
+}
+
+#[test]
+fn test_interpreter_destructuring_shape_mismatch() {
+    let interpreter = run(r#"
+        let [a, b] = [1, 2, 3];
+        let {naam} = {umer: 3};
+        let [c] = "text";
+        let x = 1;
+        let y = 2;
+        [x, y] = [3];
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(&errors[0], InterpretError::PatternMismatch(message) if message == "Expected 2 elements but the list has 3"));
+    assert!(matches!(&errors[1], InterpretError::PatternMismatch(message) if message == "Map has no key 'naam'"));
+    assert!(matches!(&errors[2], InterpretError::PatternMismatch(message) if message == "Cannot destructure a string as a list"));
+    assert!(matches!(&errors[3], InterpretError::PatternMismatch(_)));
+    // A failed destructuring assigns nothing
+    assert!(matches!(interpreter.get_variable("x"), Some(Literal::NumberLiteral(1.0))));
+}
+
+#[test]
+fn test_interpreter_tuples() {
+    let interpreter = run(r#"
+        func divmod(a, b) {
+            return ((a - a % b) / b, a % b);
+        }
+        let (q, r) = divmod(17, 5);
+
+        let grouped = (1 + 2) * 3;
+        let single = (7,);
+        let same = (1, "a") == (1, "a");
+        let different = (1, 2) == (2, 1);
+
+        let grid = {[(0, 0)]: "origin"};
+        grid[(1, 2)] = "point";
+        let origin = grid[(0, 0)];
+        let point = grid[(1, 2)];
+
+        let x = 1;
+        let y = 2;
+        (x, y) = (y, x);
+        let last = divmod(9, 4)[-1];
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("q"), Some(Literal::NumberLiteral(3.0))));
+    assert!(matches!(interpreter.get_variable("r"), Some(Literal::NumberLiteral(2.0))));
+    assert!(matches!(interpreter.get_variable("grouped"), Some(Literal::NumberLiteral(9.0))));
+    assert!(matches!(interpreter.get_variable("single"), Some(Literal::Tuple(items)) if items.len() == 1));
+    assert!(matches!(interpreter.get_variable("same"), Some(Literal::BooleanLiteral(true))));
+    assert!(matches!(interpreter.get_variable("different"), Some(Literal::BooleanLiteral(false))));
+    assert!(matches!(interpreter.get_variable("origin"), Some(Literal::StringLiteral(s)) if s == "origin"));
+    assert!(matches!(interpreter.get_variable("point"), Some(Literal::StringLiteral(s)) if s == "point"));
+    assert!(matches!(interpreter.get_variable("x"), Some(Literal::NumberLiteral(2.0))));
+    assert!(matches!(interpreter.get_variable("last"), Some(Literal::NumberLiteral(1.0))));
+}
+
+#[test]
+fn test_interpreter_tuples_are_immutable_and_lists_are_not_keys() {
+    let interpreter = run(r#"
+        let pair = (1, 2);
+        pair[0] = 5;
+        let table = {};
+        table[[1, 2]] = "list key";
+        let items = [1, 2];
+        items[5] = 3;
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], InterpretError::TypeMismatch(message) if message.contains("immutable")));
+    assert!(matches!(&errors[1], InterpretError::TypeMismatch(message) if message.starts_with("A list cannot be a map key")));
+    assert!(matches!(&errors[2], InterpretError::IndexOutOfRange(_)));
+}
+
+#[test]
+fn test_interpreter_sets() {
+    let interpreter = run(r#"
+        let primes = {2, 3, 5, 7, 3};
+        let odd = {...[1, 3, 5, 7, 9]};
+        let both = primes & odd;
+        let either = primes | odd;
+        let only_prime = primes - odd;
+        let has = 5 in primes;
+        let missing = 4 ma primes;
+        let same = {(1, 2), "a"} == {"a", (1, 2)};
+        let key = "naam" in {naam: "Ram"};
+
+        let total = 0;
+        for (n in either) {
+            if (n == 9) { break; }
+            total += n;
+        }
+        let reversed = "";
+        ko_lagi (manum c ma "abc") reversed = c + reversed;
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("primes"), Some(Literal::Set(items)) if items.len() == 4));
+    assert!(matches!(interpreter.get_variable("both"), Some(Literal::Set(items)) if items.len() == 3));
+    assert!(matches!(interpreter.get_variable("either"), Some(Literal::Set(items)) if items.len() == 6));
+    assert_eq!(interpreter.get_variable("only_prime").unwrap().to_string(), "{2}");
+    assert!(matches!(interpreter.get_variable("has"), Some(Literal::BooleanLiteral(true))));
+    assert!(matches!(interpreter.get_variable("missing"), Some(Literal::BooleanLiteral(false))));
+    assert!(matches!(interpreter.get_variable("same"), Some(Literal::BooleanLiteral(true))));
+    assert!(matches!(interpreter.get_variable("key"), Some(Literal::BooleanLiteral(true))));
+    // 2 + 3 + 5 + 7 + 1, stopping before 9
+    assert!(matches!(interpreter.get_variable("total"), Some(Literal::NumberLiteral(18.0))));
+    assert!(matches!(interpreter.get_variable("reversed"), Some(Literal::StringLiteral(s)) if s == "cba"));
+}
+
+#[test]
+fn test_interpreter_set_errors() {
+    let interpreter = run(r#"
+        let nested = {[1, 2]};
+        let mixed = {1} | [2];
+        for (x in 5) print x;
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], InterpretError::TypeMismatch(message) if message.starts_with("A list cannot be a set element")));
+    assert!(matches!(&errors[1], InterpretError::TypeMismatch(message) if message.contains("union")));
+    assert!(matches!(&errors[2], InterpretError::TypeMismatch(message) if message == "Cannot iterate over a number"));
+}
+
+#[test]
+fn test_interpreter_enums() {
+    let interpreter = run(r#"
+        enum Color { Rato, Hariyo, Nilo }
+        ganana Shape { Circle(radius), Rect(w, h), Empty }
+
+        let color = Color.Hariyo;
+        let same = color == Color.Hariyo;
+        let different = color == Color.Nilo;
+        let shown = Shape.Rect(2, 3);
+        let width = Shape.Rect(w: 4, h: 5).w;
+
+        func area(shape) {
+            milau (shape) {
+                Shape.Circle(r) => { return 3 * r * r; }
+                Shape.Rect(w, h) => { return w * h; }
+                Shape.Empty => { return 0; }
+            }
+        }
+        let circle = area(Shape.Circle(2));
+        let rect = area(Shape.Rect(2, 3));
+        let empty = area(Shape.Empty);
+        let counts = {[Color.Rato]: 1};
+        counts[Color.Rato] = counts[Color.Rato] + 1;
+        let rato = counts[Color.Rato];
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("color").unwrap().to_string(), "Color.Hariyo");
+    assert!(matches!(interpreter.get_variable("same"), Some(Literal::BooleanLiteral(true))));
+    assert!(matches!(interpreter.get_variable("different"), Some(Literal::BooleanLiteral(false))));
+    assert_eq!(interpreter.get_variable("shown").unwrap().to_string(), "Shape.Rect(2, 3)");
+    assert!(matches!(interpreter.get_variable("width"), Some(Literal::NumberLiteral(4.0))));
+    assert!(matches!(interpreter.get_variable("circle"), Some(Literal::NumberLiteral(12.0))));
+    assert!(matches!(interpreter.get_variable("rect"), Some(Literal::NumberLiteral(6.0))));
+    assert!(matches!(interpreter.get_variable("empty"), Some(Literal::NumberLiteral(0.0))));
+    assert!(matches!(interpreter.get_variable("rato"), Some(Literal::NumberLiteral(2.0))));
+}
+
+#[test]
+fn test_interpreter_enum_errors() {
+    let interpreter = run(r#"
+        enum Color { Rato }
+        let typo = Color.Rto;
+        let extra = Color.Rato.size;
+        enum Other { Rato }
+        let mixed = Color.Rato == Other.Rato;
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], InterpretError::UndefinedProperty(message) if message == "Enum 'Color' has no variant 'Rto'"));
+    assert!(matches!(&errors[1], InterpretError::UndefinedProperty(message) if message == "Color.Rato has no field 'size'"));
+    // Variants of different enums never compare equal, even with the same name
+    assert!(matches!(interpreter.get_variable("mixed"), Some(Literal::BooleanLiteral(false))));
+}
+
+#[test]
+fn test_interpreter_generators() {
+    let interpreter = run(r#"
+        func naturals() {
+            let n = 0;
+            loop { yield n; n++; }
+        }
+        func take(items, count) {
+            if (count <= 0) { return; }
+            let taken = 0;
+            for (x in items) {
+                yield x;
+                taken++;
+                if (taken == count) { break; }
+            }
+        }
+        func evens(items) {
+            for (x in items) {
+                if (x % 2 != 0) continue;
+                yield x;
+            }
+        }
+
+        let total = 0;
+        for (x in take(evens(naturals()), 4)) total += x;
+
+        let gen = take(naturals(), 2);
+        let first = next(gen);
+        let second = next(gen);
+        let finished = next(gen);
+        let fallback = next(gen, "sakiyo");
+        let collected = {...take(naturals(), 3)};
+
+        karya labels() {
+            for (let i = 0; i < 3; i++) {
+                milau (i) {
+                    0 => { pathau "sunya"; }
+                    _ => { if (i == 2) break; pathau "ek"; }
+                }
+            }
+        }
+        let words = "";
+        for (w in labels()) words = words + w + " ";
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    // 0 + 2 + 4 + 6 from an endless sequence
+    assert!(matches!(interpreter.get_variable("total"), Some(Literal::NumberLiteral(12.0))));
+    assert!(matches!(interpreter.get_variable("first"), Some(Literal::NumberLiteral(0.0))));
+    assert!(matches!(interpreter.get_variable("second"), Some(Literal::NumberLiteral(1.0))));
+    assert!(matches!(interpreter.get_variable("finished"), Some(Literal::NullLiteral)));
+    assert!(matches!(interpreter.get_variable("fallback"), Some(Literal::StringLiteral(s)) if s == "sakiyo"));
+    assert_eq!(interpreter.get_variable("collected").unwrap().to_string(), "{0, 1, 2}");
+    assert!(matches!(interpreter.get_variable("words"), Some(Literal::StringLiteral(s)) if s == "sunya ek "));
+}
+
+#[test]
+fn test_interpreter_generator_errors() {
+    let interpreter = run(r#"
+        func broken() { yield 1; yield 1 / 0; yield 3; }
+        let gen = broken();
+        let first = next(gen);
+        let failed = next(gen);
+        let after = next(gen);
+        let wrong = next([1, 2]);
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], InterpretError::DivisionByZero));
+    assert!(matches!(&errors[1], InterpretError::TypeMismatch(message) if message == "next() expects a generator as argument 1, found a list"));
+    assert!(matches!(interpreter.get_variable("first"), Some(Literal::NumberLiteral(1.0))));
+    // A generator that raised an error is finished
+    assert!(matches!(interpreter.get_variable("after"), Some(Literal::NullLiteral)));
+}
+
+#[test]
+fn test_interpreter_bound_methods() {
+    let interpreter = run(r#"
+        func count_to(limit) {
+            for (let i = 1; i <= limit; i++) yield i;
+        }
+        func apply(f, x) { return f(x); }
+        func double(x) { return x * 2; }
+
+        let gen = count_to(3);
+        let step = gen.next;
+        let first = step();
+        let second = apply(double, step());
+        let callables = [step, next, double];
+        let third = callables[1](gen, "sakiyo");
+        let done = callables[0]("sakiyo");
+        let too_many = step(1, 2);
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(matches!(interpreter.get_variable("first"), Some(Literal::NumberLiteral(1.0))));
+    assert!(matches!(interpreter.get_variable("second"), Some(Literal::NumberLiteral(4.0))));
+    assert!(matches!(interpreter.get_variable("third"), Some(Literal::NumberLiteral(3.0))));
+    assert!(matches!(interpreter.get_variable("done"), Some(Literal::StringLiteral(s)) if s == "sakiyo"));
+    assert!(matches!(&errors[0], InterpretError::ArgumentMismatch(message) if message == "Expected 0 to 1 arguments but got 2."));
+    assert_eq!(interpreter.get_variable("step").unwrap().to_string(), "<bound <native fn next> of <generator count_to>>");
+}
+
+#[test]
+fn test_interpreter_register_native() {
+    let submitted = Rc::new(RefCell::new(Vec::new()));
+    let log = Rc::clone(&submitted);
+
+    let mut interpreter = Interpreter::new();
+    interpreter.register_native("submit", 1, move |_, arguments| {
+        log.borrow_mut().push(arguments.string(0)?.to_string());
+        Ok(Literal::NullLiteral)
+    });
+    interpreter.register_native("score", Arity::range(1, 2), |_, arguments| {
+        let points = arguments.number(0)?;
+        if points < 0.0 {
+            return Err(InterpretError::UnexpectedError("score cannot be negative".to_string()));
+        }
+        let bonus = match arguments.get(1) {
+            Some(_) => arguments.number(1)?,
+            None => 0.0,
+        };
+        Ok(Literal::NumberLiteral(points + bonus))
+    });
+    interpreter.register_native("apply", 2, |interpreter, arguments| {
+        let function = arguments.callable(0)?;
+        let value = arguments.value(1)?;
+        Ok(function.call(interpreter, vec![value], Vec::new()).unwrap_or(Literal::NullLiteral))
+    });
+
+    let source = r#"
+        submit("pahilo");
+        let total = score(8, 2);
+        func double(x) { return x * 2; }
+        let doubled = apply(double, 21);
+        let caught = "";
+        try { score(-1); } catch (e) { caught = e.message; }
+        submit(42);
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    let _ = interpreter.interpret(&ast);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::TypeMismatch(message) if message == "submit() expects a string as argument 1, found a number"));
+    assert_eq!(*submitted.borrow(), vec!["pahilo".to_string()]);
+    assert!(matches!(interpreter.get_variable("total"), Some(Literal::NumberLiteral(10.0))));
+    assert!(matches!(interpreter.get_variable("doubled"), Some(Literal::NumberLiteral(42.0))));
+    assert!(matches!(interpreter.get_variable("caught"), Some(Literal::StringLiteral(s)) if s == "score cannot be negative"));
+}
+
+#[test]
+fn test_interpreter_prelude() {
+    let interpreter = run(r#"
+        let kinds = [type(1), prakar("a"), type(satya), type(khali), type([]), type({}), type(len)];
+        let parsed = num(" 42.5 ") + sankhya(galat);
+        let text = str(7) + shabda([1, 2]);
+        let flags = [bool("true"), satyata("galat"), bool(0), bool(khali)];
+        let sizes = [len("नेपाल"), lambai([1, 2, 3]), len((1,)), len({"a": 1}), len({1, 2})];
+        let started = clock();
+        let elapsed = samaya() - started;
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("kinds").unwrap().to_string(), "[number, string, boolean, null, list, map, function]");
+    assert!(matches!(interpreter.get_variable("parsed"), Some(Literal::NumberLiteral(42.5))));
+    assert!(matches!(interpreter.get_variable("text"), Some(Literal::StringLiteral(s)) if s == "7[1, 2]"));
+    assert_eq!(interpreter.get_variable("flags").unwrap().to_string(), "[true, false, true, false]");
+    assert_eq!(interpreter.get_variable("sizes").unwrap().to_string(), "[5, 3, 1, 1, 2]");
+    assert!(matches!(interpreter.get_variable("elapsed"), Some(Literal::NumberLiteral(n)) if n >= 0.0));
+}
+
+#[test]
+fn test_interpreter_prelude_errors() {
+    let interpreter = run(r#"
+        let messages = "";
+        try { num("abc"); } catch (e) { messages = messages + e.message + "; "; }
+        try { num([1]); } catch (e) { messages = messages + e.message + "; "; }
+        try { bool("maybe"); } catch (e) { messages = messages + e.message + "; "; }
+        try { len(5); } catch (e) { messages = messages + e.message; }
+        len();
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::ArgumentMismatch(_)));
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        r#"num() cannot turn "abc" into a number; num() cannot turn a list into a number; bool() cannot turn "maybe" into a boolean; len() expects a string, list, tuple, map or set, found a number"#
+    );
+}
+
+#[test]
+fn test_interpreter_input() {
+    let mut interpreter = Interpreter::new();
+    interpreter.set_input(Cursor::new("Sita\r\n21\n"));
+
+    let source = r#"
+        let naam = input("Naam: ");
+        let umer = num(lekha());
+        let ended = input();
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    let _ = interpreter.interpret(&ast);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("naam"), Some(Literal::StringLiteral(s)) if s == "Sita"));
+    assert!(matches!(interpreter.get_variable("umer"), Some(Literal::NumberLiteral(21.0))));
+    assert!(matches!(interpreter.get_variable("ended"), Some(Literal::NullLiteral)));
+}
+
+#[test]
+fn test_interpreter_string_methods() {
+    let interpreter = run(r#"
+        let desh = "नमस्ते नेपाल";
+        let parts = [desh.slice(7), desh.tukra(0, 6), "abcdef".slice(-3, -1), "abc".slice(2, 1)];
+        let found = [desh.find("नेपाल"), "abc".khoja("z")];
+        let replaced = "a-b-c".replace("-", "+");
+        let words = "  ek  dui tin ".split();
+        let fields = "a,b,,c".chhuttyau(",");
+        let letters = "नेपाल".split("");
+        let joined = ", ".join([1, "dui", satya]);
+        let cleaned = "  Ram  ".trim().upper() + "MAYA".sano();
+        let checks = ["file.csv".ends_with(".csv"), "file.csv".suru_huncha("x")];
+        let line = "=".repeat(3);
+        let upper = "ram".upper;
+        let shouted = [upper(), type(upper), "ram".thulo == upper];
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("parts").unwrap().to_string(), "[नेपाल, नमस्ते, de, ]");
+    assert_eq!(interpreter.get_variable("found").unwrap().to_string(), "[7, -1]");
+    assert!(matches!(interpreter.get_variable("replaced"), Some(Literal::StringLiteral(s)) if s == "a+b+c"));
+    assert_eq!(interpreter.get_variable("words").unwrap().to_string(), "[ek, dui, tin]");
+    assert_eq!(interpreter.get_variable("fields").unwrap().to_string(), "[a, b, , c]");
+    assert_eq!(interpreter.get_variable("letters").unwrap().to_string(), "[न, े, प, ा, ल]");
+    assert!(matches!(interpreter.get_variable("joined"), Some(Literal::StringLiteral(s)) if s == "1, dui, true"));
+    assert!(matches!(interpreter.get_variable("cleaned"), Some(Literal::StringLiteral(s)) if s == "RAMmaya"));
+    assert_eq!(interpreter.get_variable("checks").unwrap().to_string(), "[true, false]");
+    assert!(matches!(interpreter.get_variable("line"), Some(Literal::StringLiteral(s)) if s == "==="));
+    assert_eq!(interpreter.get_variable("upper").unwrap().to_string(), "<bound <native fn upper> of ram>");
+    assert_eq!(interpreter.get_variable("shouted").unwrap().to_string(), "[RAM, function, false]");
+}
+
+#[test]
+fn test_interpreter_string_method_errors() {
+    let interpreter = run(r#"
+        let messages = "";
+        try { "abc".slice(1.5); } catch (e) { messages = messages + e.message + "; "; }
+        try { "abc".find(1); } catch (e) { messages = messages + e.message + "; "; }
+        try { "-".repeat(-1); } catch (e) { messages = messages + e.message + "; "; }
+        try { "abc".repeat(100000000000000000000); } catch (e) { messages = messages + e.message + "; "; }
+        try { "abc".upper(1); } catch (e) { messages = messages + e.message + "; "; }
+        try { ",".join("abc"); } catch (e) { messages = messages + e.message; }
+        "abc".shout();
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::UndefinedProperty(message) if message == "Undefined property 'shout' on abc"));
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "slice() expects a whole number as argument 1, found a number; find() expects a string as argument 1, found a number; \
+         repeat() cannot repeat a string -1 times; repeat() cannot make a string longer than 100000000 bytes; \
+         Expected 0 arguments but got 1.; join() expects a list or tuple as argument 1, found a string"
+    );
+}
+
+#[test]
+fn test_interpreter_math_module() {
+    let interpreter = run(r#"
+        let values = [math.sqrt(16), ganit.pow(2, 10), math.abs(-3), math.floor(2.7), math.ceil(2.1), math.round(2.5), math.min(3, 1, 2), math.max(3, 1, 2)];
+        let circle = math.round(math.PI * 10000);
+        let trig = [math.sin(0), math.cos(0), math.round(math.atan2(1, 1) * 4 / math.PI)];
+
+        math.seed(42);
+        let first = [math.random(), math.random(), math.random_int(1, 6)];
+        ganit.seed(42);
+        let again = [math.random(), math.random(), math.random_int(1, 6)];
+
+        let wide = math.random_int(-9000000000000000000, 9000000000000000000);
+        let widest = math.random_int(-10000000000000000000000, 10000000000000000000000);
+        let in_range = wide >= -9000000000000000000 and wide <= 9000000000000000000;
+        for (let i = 0; i < 100; i++) {
+            let r = math.random();
+            let d = math.random_int(1, 6);
+            if (r < 0 or r >= 1 or d < 1 or d > 6) in_range = galat;
+        }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("values").unwrap().to_string(), "[4, 1024, 3, 2, 3, 3, 1, 3]");
+    assert!(matches!(interpreter.get_variable("circle"), Some(Literal::NumberLiteral(31416.0))));
+    assert_eq!(interpreter.get_variable("trig").unwrap().to_string(), "[0, 1, 1]");
+    assert_eq!(
+        interpreter.get_variable("first").unwrap().to_string(),
+        interpreter.get_variable("again").unwrap().to_string()
+    );
+    assert!(matches!(interpreter.get_variable("in_range"), Some(Literal::BooleanLiteral(true))));
+    // Whole numbers beyond i64 saturate, so this is the full i64 range
+    assert!(matches!(interpreter.get_variable("widest"), Some(Literal::NumberLiteral(_))));
+}
+
+#[test]
+fn test_interpreter_math_errors() {
+    let interpreter = run(r#"
+        let messages = "";
+        try { math.sqrt(-1); } catch (e) { messages = messages + e.message + "; "; }
+        try { math.random_int(6, 1); } catch (e) { messages = messages + e.message + "; "; }
+        try { math.max("a", 1); } catch (e) { messages = messages + e.message; }
+        math.tau;
+    "#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::UndefinedProperty(message) if message == "Module 'math' does not export 'tau'"));
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "sqrt() is not defined for -1; random_int() needs low <= high, got 6 and 1; max() expects a number as argument 1, found a string"
+    );
+}
+
+#[test]
+fn test_interpreter_sandboxed_files() {
+    let dir = std::env::temp_dir().join(format!("neplat_files_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("data")).unwrap();
+    fs::write(dir.join("data/marks.csv"), "naam,ank\nSita,90\n").unwrap();
+
+    let mut interpreter = Interpreter::new();
+    interpreter.set_file_access(FileAccess::new(&dir).unwrap());
+    let source = r#"
+        let rows = files.read("data/marks.csv").split();
+        kagaj.write("out.txt", "pahilo ");
+        files.append("out.txt", 2);
+        let written = files.read("./out.txt");
+        let checks = [files.exists("data/marks.csv"), files.exists("missing.txt")];
+        let listed = files.list();
+        let kinds = "";
+        try { files.read("missing.txt"); } catch (e) { kinds = kinds + e.kind + " "; }
+        try { files.read("../secret.txt"); } catch (e) { kinds = kinds + e.message + "; "; }
+        try { files.write("/tmp/escape.txt", "x"); } catch (e) { kinds = kinds + e.message; }
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    let _ = interpreter.interpret(&ast);
+    let _ = fs::remove_dir_all(&dir);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("rows").unwrap().to_string(), "[naam,ank, Sita,90]");
+    assert!(matches!(interpreter.get_variable("written"), Some(Literal::StringLiteral(s)) if s == "pahilo 2"));
+    assert_eq!(interpreter.get_variable("checks").unwrap().to_string(), "[true, false]");
+    assert_eq!(interpreter.get_variable("listed").unwrap().to_string(), "[data, out.txt]");
+    assert_eq!(
+        interpreter.get_variable("kinds").unwrap().to_string(),
+        "FileError Cannot use '../secret.txt'; it is outside the allowed directory; \
+         Cannot use '/tmp/escape.txt'; it is outside the allowed directory"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_interpreter_files_refuse_links_out_of_the_root() {
+    use std::os::unix::fs::symlink;
+
+    let base = std::env::temp_dir().join(format!("neplat_links_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&base);
+    let (root, outside) = (base.join("root"), base.join("outside"));
+    fs::create_dir_all(&root).unwrap();
+    fs::create_dir_all(&outside).unwrap();
+    symlink(&outside, root.join("away")).unwrap();
+    symlink(outside.join("new.txt"), root.join("dangling")).unwrap();
+
+    let mut interpreter = Interpreter::new();
+    interpreter.set_file_access(FileAccess::new(&root).unwrap());
+    let source = r#"
+        let messages = "";
+        try { files.write("away/note.txt", "x"); } catch (e) { messages = messages + e.message + "; "; }
+        try { files.write("dangling", "x"); } catch (e) { messages = messages + e.message + "; "; }
+        try { files.write("dangling/../inside.txt", "x"); } catch (e) { messages = messages + e.message; }
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    let _ = interpreter.interpret(&ast);
+    let escaped = [outside.join("note.txt").exists(), outside.join("new.txt").exists()];
+    let inside = root.join("inside.txt").exists();
+    let _ = fs::remove_dir_all(&base);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(escaped, [false, false]);
+    assert!(inside);
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "Cannot use 'away/note.txt'; it is outside the allowed directory; \
+         Cannot use 'dangling'; it is outside the allowed directory; "
+    );
+}
+
+#[test]
+fn test_interpreter_file_access_is_off_by_default() {
+    let interpreter = run(r#"files.exists("anything.txt");"#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::FileError(message) if message == "Cannot use 'anything.txt'; file access is turned off"));
+}
+
+#[test]
+fn test_interpreter_json() {
+    let interpreter = run(r#"
+        let compact = json.stringify({"naam": "सीता", "ank": [90, 85.5], "pass": satya, "note": khali});
+        let data = json.parse(compact);
+        let second = data.ank[1];
+        let pretty = json.stringify({"a": [1, {}], "b": []}, 2);
+        let shapes = json.stringify([(1, 2), {3}]);
+        let nested = json.parse("[[[]], -1e2, 0.5]");
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert!(matches!(interpreter.get_variable("compact"), Some(Literal::StringLiteral(s)) if s == r#"{"naam":"सीता","ank":[90,85.5],"pass":true,"note":null}"#));
+    assert!(matches!(interpreter.get_variable("second"), Some(Literal::NumberLiteral(85.5))));
+    assert_eq!(interpreter.get_variable("data").unwrap().to_string(), "{naam: सीता, ank: [90, 85.5], pass: true, note: Null}");
+    assert!(matches!(interpreter.get_variable("pretty"), Some(Literal::StringLiteral(s)) if s == "{\n  \"a\": [\n    1,\n    {}\n  ],\n  \"b\": []\n}"));
+    assert!(matches!(interpreter.get_variable("shapes"), Some(Literal::StringLiteral(s)) if s == "[[1,2],[3]]"));
+    assert_eq!(interpreter.get_variable("nested").unwrap().to_string(), "[[[]], -100, 0.5]");
+}
+
+#[test]
+fn test_json_parse_and_errors() {
+    let value = json::parse(r#" {"a": "q\"\u0041\ud83d\ude00", "a": [1, {"b": null}], "c": false} "#).unwrap();
+    assert_eq!(json::stringify(&value, 0).unwrap(), r#"{"a":[1,{"b":null}],"c":false}"#);
+    assert_eq!(
+        json::stringify(&json::parse(r#""q\"\u0041\ud83d\ude00\n""#).unwrap(), 0).unwrap(),
+        r#""q\"A😀\n""#
+    );
+
+    let message = |text: &str| json::parse(text).unwrap_err().message();
+    assert_eq!(message("{\"a\": 1,\n  \"b\" 2}"), "Invalid JSON at line 2, column 7: expected ':'");
+    assert_eq!(message("[1, 2"), "Invalid JSON at line 1, column 6: expected ',' or ']'");
+    assert_eq!(message("tru"), "Invalid JSON at line 1, column 4: expected 'true'");
+    assert_eq!(message("[1] x"), "Invalid JSON at line 1, column 5: unexpected text after the value");
+    assert_eq!(message("01"), "Invalid JSON at line 1, column 2: unexpected text after the value");
+    assert_eq!(message(&"[".repeat(300)), "Invalid JSON at line 1, column 258: nested too deeply");
+}
+
+#[test]
+fn test_interpreter_json_errors() {
+    let interpreter = run(r#"
+        let messages = "";
+        try { json.parse("[1,
+ 2 3]"); } catch (e) { messages = messages + e.kind + ": " + e.message + "; "; }
+        try { json.stringify({[(1, 2)]: 1}); } catch (e) { messages = messages + e.message + "; "; }
+        try { json.stringify(len); } catch (e) { messages = messages + e.message; }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "JsonError: Invalid JSON at line 2, column 4: expected ',' or ']'; \
+         JSON object keys must be strings, found a tuple; \
+         Cannot write a function as JSON"
+    );
+}
+
+#[test]
+fn test_interpreter_bikram_sambat_dates() {
+    let interpreter = run(r#"
+        let new_year = date.to_bs(2024, 4, 13);
+        let dashain = miti.to_bs({"year": 2024, "month": 10, "day": 12});
+        let back = date.to_ad(2081, 6, 26);
+        let month_start = [date.to_ad(2081, 6), date.to_bs(2025, 1)];
+        let shown = date.format(new_year);
+        let last_day = date.add_days(new_year, -1);
+        let later = date.add_days(dashain, 100);
+        let between = date.days_between(new_year, date.to_bs(2025, 4, 14));
+        let lengths = [date.days_in_month(2081, 1), date.days_in_month(2081, 9)];
+        let today = date.today();
+        let now = date.now();
+        let clock_ok = now.hour >= 0 and now.hour < 24 and now.minute < 60;
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("new_year").unwrap().to_string(), "{year: 2081, month: 1, day: 1, weekday: 6}");
+    assert_eq!(interpreter.get_variable("dashain").unwrap().to_string(), "{year: 2081, month: 6, day: 26, weekday: 6}");
+    assert_eq!(interpreter.get_variable("back").unwrap().to_string(), "{year: 2024, month: 10, day: 12, weekday: 6}");
+    assert_eq!(
+        interpreter.get_variable("month_start").unwrap().to_string(),
+        "[{year: 2024, month: 9, day: 17, weekday: 2}, {year: 2081, month: 9, day: 17, weekday: 3}]"
+    );
+    assert!(matches!(interpreter.get_variable("shown"), Some(Literal::StringLiteral(s)) if s == "Sanibar 1 Baisakh, 2081"));
+    assert_eq!(interpreter.get_variable("last_day").unwrap().to_string(), "{year: 2080, month: 12, day: 30, weekday: 5}");
+    assert_eq!(interpreter.get_variable("later").unwrap().to_string(), "{year: 2081, month: 10, day: 7, weekday: 1}");
+    assert!(matches!(interpreter.get_variable("between"), Some(Literal::NumberLiteral(366.0))));
+    assert_eq!(interpreter.get_variable("lengths").unwrap().to_string(), "[31, 29]");
+    assert!(matches!(interpreter.get_variable("today"), Some(Literal::Map(_))));
+    assert!(matches!(interpreter.get_variable("clock_ok"), Some(Literal::BooleanLiteral(true))));
+}
+
+#[test]
+fn test_interpreter_date_errors() {
+    let interpreter = run(r#"
+        let messages = "";
+        try { date.to_bs(2023, 2, 29); } catch (e) { messages = messages + e.kind + ": " + e.message + "; "; }
+        try { date.to_ad(2081, 13, 1); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.to_ad(2081, 1, 32); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.to_ad(2200, 1, 1); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.to_bs(1900, 1, 1); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.format({"year": 2081}); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.add_days(date.to_bs(2024, 4, 13), 100000000000000000000); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.add_days(date.to_bs(2024, 4, 13), 1000000000); } catch (e) { messages = messages + e.message + "; "; }
+        try { date.to_bs(100000000000000000000, 1, 1); } catch (e) { messages = messages + e.message; }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "DateError: 2023-02-29 is not a valid AD date; \
+         13 is not a month; months go from 1 (Baisakh) to 12 (Chaitra); \
+         2081 Baisakh 32 is not a valid BS date; \
+         Only Bikram Sambat years 2000 to 2090 are supported, not 2200; \
+         1900-01-01 AD is outside the Bikram Sambat years 2000 to 2090; \
+         A date needs a whole number 'month'; \
+         Cannot add 9223372036854775807 days to a date; \
+         That date is outside the Bikram Sambat years 2000 to 2090; \
+         Only AD years 1 to 9999 are supported, not 9223372036854775807"
+    );
+}
+
+#[test]
+fn test_interpreter_nepali_numbers() {
+    let interpreter = run(r#"
+        let grouped = [numbers.group(10000000), ank.group(1234567.5), numbers.group(-999), numbers.group(123456, 2), numbers.group(0.004, 2)];
+        let spelled = numbers.words(123456789);
+        let small = [numbers.words(0), numbers.words(19), numbers.words(-100)];
+        let nepali = ank.words(1125000, satya);
+        let cheque = numbers.rupees(12050.75);
+        let cheque_np = numbers.rupees(1.5, satya);
+        let only_paisa = numbers.rupees(0.25);
+        let digits = ank.devanagari(numbers.group(12345678.9));
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(interpreter.get_variable("grouped").unwrap().to_string(), "[1,00,00,000, 12,34,567.5, -999, 1,23,456.00, 0.00]");
+    assert!(matches!(interpreter.get_variable("spelled"), Some(Literal::StringLiteral(s)) if s == "bahra crore chauntis lakh chhapanna hajar sat saya unanabbe"));
+    assert_eq!(interpreter.get_variable("small").unwrap().to_string(), "[sunya, unnais, rin ek saya]");
+    assert!(matches!(interpreter.get_variable("nepali"), Some(Literal::StringLiteral(s)) if s == "एघार लाख पच्चीस हजार"));
+    assert!(matches!(interpreter.get_variable("cheque"), Some(Literal::StringLiteral(s)) if s == "bahra hajar pachas rupaiya pachahattar paisa"));
+    assert!(matches!(interpreter.get_variable("cheque_np"), Some(Literal::StringLiteral(s)) if s == "एक रुपैयाँ पचास पैसा"));
+    assert!(matches!(interpreter.get_variable("only_paisa"), Some(Literal::StringLiteral(s)) if s == "pachchis paisa"));
+    assert!(matches!(interpreter.get_variable("digits"), Some(Literal::StringLiteral(s)) if s == "१,२३,४५,६७८.९"));
+}
+
+#[test]
+fn test_interpreter_nepali_number_errors() {
+    let interpreter = run(r#"
+        let messages = "";
+        try { numbers.words(2.5); } catch (e) { messages = messages + e.message + "; "; }
+        try { numbers.words(10000000000000); } catch (e) { messages = messages + e.message + "; "; }
+        try { numbers.words(-100000000000000000000); } catch (e) { messages = messages + e.message + "; "; }
+        try { numbers.rupees("100"); } catch (e) { messages = messages + e.message; }
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(
+        interpreter.get_variable("messages").unwrap().to_string(),
+        "words() expects a whole number as argument 1, found a number; \
+         words() can only spell numbers up to 99,99,99,99,99,999; \
+         words() can only spell numbers up to 99,99,99,99,99,999; \
+         rupees() expects a number as argument 1, found a string"
+    );
+}
+
+#[test]
+fn test_interpreter_transliteration() {
+    let interpreter = run(r#"
+        let words = [
+            lipi("namaste"), lipi("nepaal"), lipi("raam"), lipi("bhagawaan.h"), lipi("kShetra"),
+            lipi("chhoraa chhorii"), lipi("saMsaar"), lipi("haa.Nsa"), lipi("Thik chha|"), lipi("ka_i ga_ii"), lipi("2081 saal")
+        ];
+        // Only capitals that name another letter change the result
+        let cased = [lipi("Kamal"), lipi("Sita"), lipi("Namaste"), lipi("Raam")];
+        let back = [
+            lipi("नमस्ते", "roman"), lipi("भगवान्", "roman"), lipi("गई", "roman"), lipi("अन्य", "roman"),
+            transliterate("ज्ञान", "roman"), lipi("२०८१ साल।", "roman")
+        ];
+        let round_trip = lipi(lipi("नेपाली भाषा", "roman"));
+    "#);
+
+    assert!(interpreter.get_errors().is_empty(), "{:?}", interpreter.get_errors());
+    assert_eq!(
+        interpreter.get_variable("words").unwrap().to_string(),
+        "[नमस्ते, नेपाल, राम, भगवान्, क्षेत्र, छोरा छोरी, संसार, हाँस, ठिक छ।, कइ गई, २०८१ साल]"
+    );
+    assert_eq!(interpreter.get_variable("cased").unwrap().to_string(), "[कमल, सित, णमस्ते, राम]");
+    assert_eq!(
+        interpreter.get_variable("back").unwrap().to_string(),
+        "[namaste, bhagawaan.h, ga_ii, anya, j~naana, 2081 saala|]"
+    );
+    assert!(matches!(interpreter.get_variable("round_trip"), Some(Literal::StringLiteral(s)) if s == "नेपाली भाषा"));
+}
+
+#[test]
+fn test_interpreter_transliteration_errors() {
+    let interpreter = run(r#"lipi("namaste", "latin");"#);
+
+    let errors = interpreter.get_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], InterpretError::ArgumentMismatch(message) if message == r#"transliterate() can write "devanagari" or "roman", not "latin""#));
+}
@@ -203,3 +203,34 @@ fn test_mix_language_code() {
 
     assert_eq!(tokens.last().unwrap().token_type(), &TokenType::EOF);
 }
+
+#[test]
+fn test_compound_assignment_operators() {
+    let mut lexer = Lexer::new("+= -= *= /= %= ++ -- joda_bhaneko ek_ghatau".as_bytes());
+    let tokens = lexer.tokenize();
+
+    assert_eq!(tokens.len(), 10);
+    assert_eq!(tokens[0].token_type(), &TokenType::PLUSEQUAL);
+    assert_eq!(tokens[1].token_type(), &TokenType::MINUSEQUAL);
+    assert_eq!(tokens[2].token_type(), &TokenType::STAREQUAL);
+    assert_eq!(tokens[3].token_type(), &TokenType::SLASHEQUAL);
+    assert_eq!(tokens[4].token_type(), &TokenType::PERCENTEQUAL);
+    assert_eq!(tokens[5].token_type(), &TokenType::PLUSPLUS);
+    assert_eq!(tokens[6].token_type(), &TokenType::MINUSMINUS);
+    assert_eq!(tokens[7].token_type(), &TokenType::PLUSEQUAL);
+    assert_eq!(tokens[8].token_type(), &TokenType::MINUSMINUS);
+}
+
+#[test]
+fn test_decimal_and_range_numbers() {
+    let mut lexer = Lexer::new("1.5 1..5 1..=5".as_bytes());
+    let tokens = lexer.tokenize();
+
+    assert!(lexer.get_errors().is_empty());
+    assert_eq!(tokens[0].lexeme(), "1.5");
+    assert_eq!(tokens[1].lexeme(), "1");
+    assert_eq!(tokens[2].token_type(), &TokenType::DOTDOT);
+    assert_eq!(tokens[3].lexeme(), "5");
+    assert_eq!(tokens[5].token_type(), &TokenType::DOTDOTEQUAL);
+}
+
@@ -2,8 +2,8 @@ use std::vec;
 
 use neplat::{
     lexer::{Literal, Token, TokenType},
-    parser::{expr::Expr, parser_error::ParserError, stmt::Stmt},
-    Parser,
+    parser::{expr::Expr, parser_error::ParserError, pattern::Pattern, stmt::Stmt},
+    Lexer, Parser,
 };
 
 #[test]
@@ -158,3 +158,192 @@ fn test_parse_unclosed_parenthesis_error() {
         ParserError::UnclosedParen { line: 1, .. }
     ));
 }
+
+#[test]
+fn test_parse_parameter_defaults_and_rest() {
+    let tokens = Lexer::new("func f(a, b = 1, ...c) {}".as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty());
+    if let Stmt::Function(_, params, _) = &ast[0] {
+        assert_eq!(params.len(), 3);
+        assert!(params[0].default.is_none() && !params[0].rest);
+        assert!(params[1].default.is_some());
+        assert!(params[2].rest);
+    } else {
+        panic!("Expected a function declaration");
+    }
+}
+
+#[test]
+fn test_parse_rest_parameter_must_be_last() {
+    let tokens = Lexer::new("func f(...a, b) {}".as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    parser.parse();
+
+    assert!(matches!(
+        parser.get_errors()[0],
+        ParserError::InvalidDecleration { line: 1, .. }
+    ));
+}
+
+#[test]
+fn test_parse_named_arguments() {
+    let tokens = Lexer::new("f(1, naam: 2);".as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty());
+    if let Stmt::Expression(Expr::Call(_, _, args, named, _)) = &ast[0] {
+        assert_eq!(args.len(), 1);
+        assert_eq!(named.len(), 1);
+        assert_eq!(named[0].0.lexeme(), "naam");
+    } else {
+        panic!("Expected a call expression");
+    }
+}
+
+#[test]
+fn test_parse_nepali_conditional_as_statement() {
+    let source = r#"
+        yadi (ready) bhane start() natra wait();
+        yadi (ready) { start(); }
+        let bhane = 1;
+        let pair = {a: 1};
+        let wrong = {a bhane 1};
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(matches!(&ast[0], Stmt::Expression(Expr::Conditional(..))));
+    assert!(matches!(&ast[1], Stmt::If(..)));
+    // `bhane` is an ordinary name outside the conditional, so it no longer stands in for `:`
+    assert_eq!(ast.len(), 4);
+    assert!(!parser.get_errors().is_empty());
+    assert!(parser.get_errors().iter().all(|error| matches!(error, ParserError::UnclosedParen { line: 6, .. } | ParserError::ExpectedExpression { line: 6, .. })));
+}
+
+#[test]
+fn test_parse_conditional_binds_looser_than_or() {
+    let tokens = Lexer::new("x = a or b ? 1 : yadi c bhane 2 natra 3;".as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty());
+    if let Stmt::Expression(Expr::Assign(_, value)) = &ast[0] {
+        if let Expr::Conditional(condition, _, else_branch) = &**value {
+            assert!(matches!(**condition, Expr::Logical(..)));
+            assert!(matches!(**else_branch, Expr::Conditional(..)));
+        } else {
+            panic!("Expected a conditional expression");
+        }
+    } else {
+        panic!("Expected an assignment");
+    }
+}
+
+#[test]
+fn test_parse_match_arms() {
+    let source = r#"
+        milau (x) {
+            0 => print "zero";
+            -5..=5 yadi x > 0 => print "small";
+            [first, ...rest] => print first;
+            _ => print "other";
+        }
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty());
+    if let Stmt::Match(_, _, arms) = &ast[0] {
+        assert_eq!(arms.len(), 4);
+        assert!(matches!(arms[0].pattern, Pattern::Literal(Literal::NumberLiteral(0.0))));
+        assert!(matches!(arms[1].pattern, Pattern::Range(-5.0, 5.0, true)));
+        assert!(arms[1].guard.is_some());
+        assert!(matches!(&arms[2].pattern, Pattern::List(elements, Some(_)) if elements.len() == 1));
+        assert!(matches!(arms[3].pattern, Pattern::Wildcard));
+    } else {
+        panic!("Expected a match statement");
+    }
+}
+
+
+#[test]
+fn test_parse_loop_forms() {
+    let source = r#"
+        gara { x++; } jaba_samma (x < 3);
+        dohoryau { roka; }
+        for (let i = 0; i < 3; i++) { continue; }
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty());
+    assert!(matches!(ast[0], Stmt::DoWhile(_, _)));
+    assert!(matches!(&ast[1], Stmt::Loop(body) if matches!(body.as_ref(), Stmt::Block(stmts) if matches!(*stmts[0], Stmt::Break(_)))));
+    // The for increment stays on the loop so `continue` cannot skip it
+    assert!(matches!(&ast[2], Stmt::Block(stmts) if matches!(*stmts[1], Stmt::While(_, _, Some(_)))));
+}
+
+#[test]
+fn test_parse_destructuring() {
+    let source = r#"
+        let {naam, umer: age} = person;
+        [a, b] = [b, a];
+        [a, 1] = pair;
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(matches!(&ast[0], Stmt::Destructure(Pattern::Map(entries), _, false) if entries.len() == 2));
+    assert!(matches!(&ast[1], Stmt::Expression(Expr::Destructure(Pattern::List(targets, None), _, _)) if targets.len() == 2));
+    // Only names, lists and maps can be assigned to
+    assert!(matches!(parser.get_errors()[0], ParserError::InvalidAssignment { line: 4, .. }));
+}
+
+#[test]
+fn test_parse_braces_as_map_or_set() {
+    let source = r#"
+        let empty = {};
+        let map = {[key]: 1};
+        let set = {[1, 2], ...rest};
+        for (x in a | b & c) print x;
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty());
+    assert!(matches!(&ast[0], Stmt::Var(_, Some(Expr::Map(entries))) if entries.is_empty()));
+    assert!(matches!(&ast[1], Stmt::Var(_, Some(Expr::Map(entries))) if entries.len() == 1));
+    assert!(matches!(&ast[2], Stmt::Var(_, Some(Expr::Set(elements))) if elements.len() == 2 && elements[1].1));
+    // `&` binds tighter than `|`
+    assert!(matches!(&ast[3], Stmt::ForIn(_, Expr::Binary(_, operator, right), _)
+        if operator.token_type() == &TokenType::PIPE && matches!(right.as_ref(), Expr::Binary(_, _, _))));
+}
+
+#[test]
+fn test_parse_enum_and_variant_patterns() {
+    let source = r#"
+        enum Shape { Circle(radius), Rect(w, h), Empty, }
+        milau (s) {
+            Shape.Circle(r) => print r;
+            geo.Shape.Empty => print 0;
+        }
+    "#;
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+
+    assert!(parser.get_errors().is_empty(), "{:?}", parser.get_errors());
+    assert!(matches!(&ast[0], Stmt::Enum(name, variants) if name.lexeme() == "Shape" && variants.len() == 3 && variants[1].1.len() == 2));
+    assert!(matches!(&ast[1], Stmt::Match(_, _, arms)
+        if matches!(&arms[0].pattern, Pattern::Variant(path, name, Some(fields)) if path.len() == 1 && name.lexeme() == "Circle" && fields.len() == 1)
+        && matches!(&arms[1].pattern, Pattern::Variant(path, _, None) if path.len() == 2)));
+}
@@ -0,0 +1,117 @@
+use neplat::{resolver::resolver_error::ResolverError, Lexer, Parser, Resolver};
+
+fn resolve(source: &str) -> Resolver {
+    let tokens = Lexer::new(source.as_bytes()).tokenize();
+    let mut parser = Parser::new(&tokens);
+    let ast = parser.parse();
+    assert!(parser.get_errors().is_empty(), "{:?}", parser.get_errors());
+
+    let mut resolver = Resolver::new();
+    resolver.resolve(&ast);
+    resolver
+}
+
+#[test]
+fn test_resolver_rejects_constant_reassignment() {
+    let resolver = resolve(r#"
+        const pi = 3.14;
+        sthir naam = "NepLat";
+        pi = 3;
+        naam += "!";
+        {
+            pi++;
+        }
+    "#);
+
+    let errors = resolver.get_errors();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], ResolverError::ConstantReassignment { line: 4, lexeme } if lexeme == "pi"));
+    assert!(matches!(&errors[1], ResolverError::ConstantReassignment { line: 5, lexeme } if lexeme == "naam"));
+    assert!(matches!(&errors[2], ResolverError::ConstantReassignment { line: 7, .. }));
+}
+
+#[test]
+fn test_resolver_allows_shadowing_constants() {
+    let resolver = resolve(r#"
+        const limit = 10;
+        {
+            let limit = 1;
+            limit = 2;
+        }
+        func grow(limit) {
+            limit += 1;
+        }
+        match (5) {
+            limit => limit = 0;
+        }
+    "#);
+
+    assert!(resolver.get_errors().is_empty(), "{:?}", resolver.get_errors());
+}
+
+#[test]
+fn test_resolver_rejects_break_outside_loop() {
+    let resolver = resolve(r#"
+        loop {
+            func helper() {
+                break;
+            }
+            break;
+        }
+        continue;
+    "#);
+
+    let errors = resolver.get_errors();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], ResolverError::OutsideLoop { line: 4, .. }));
+    assert!(matches!(&errors[1], ResolverError::OutsideLoop { line: 8, lexeme } if lexeme == "continue"));
+}
+
+#[test]
+fn test_resolver_checks_enum_matches() {
+    let resolver = resolve(r#"
+        enum Color { Rato, Hariyo, Nilo }
+        milau (c) {
+            Color.Rato => print 1;
+            Color.Hariyo if ready => print 2;
+        }
+        milau (c) {
+            Color.Rto => print 1;
+            _ => print 2;
+        }
+        ganana Shape { Circle(radius), Empty }
+        milau (s) {
+            Shape.Circle(r) => print r;
+            Shape.Empty => print 0;
+        }
+        milau (s) {
+            Shape.Circle(0) => print 0;
+            other => print other;
+        }
+    "#);
+
+    let errors = resolver.get_errors();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    // A guarded arm does not count as covering its variant
+    assert!(matches!(&errors[0], ResolverError::NonExhaustiveMatch { line: 3, missing, .. }
+        if missing == &vec!["Color.Hariyo".to_string(), "Color.Nilo".to_string()]));
+    assert!(matches!(&errors[1], ResolverError::UnknownVariant { line: 8, lexeme } if lexeme == "Color.Rto"));
+}
+
+#[test]
+fn test_resolver_rejects_misplaced_yield() {
+    let resolver = resolve(r#"
+        yield 1;
+        func numbers() {
+            try {
+                yield 2;
+            } catch (e) {}
+            yield 3;
+        }
+    "#);
+
+    let errors = resolver.get_errors();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(&errors[0], ResolverError::YieldOutsideFunction { line: 2, .. }));
+    assert!(matches!(&errors[1], ResolverError::YieldInsideTry { line: 5, .. }));
+}